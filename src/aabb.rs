@@ -1,4 +1,10 @@
-use crate::{Intersects, Point};
+use crate::{
+    Intersects, Point, Ray, RayCast, RayHit, Rotation, Support, Transform, Transformable, Vector,
+};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
 
 /// An axix-aligned bounding box
 ///
@@ -12,7 +18,13 @@ use crate::{Intersects, Point};
 /// │
 /// y
 /// ```
-#[derive(Debug, PartialEq, Copy, Clone)]
+///
+/// Deserialization goes through [`Aabb::try_new`],
+/// so boxes without a positive width and height are rejected.
+///
+/// [`Aabb::try_new`]: ./struct.Aabb.html#method.try_new
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedAabb")]
 pub struct Aabb {
     /// The coordinates of the upper left corner of the box
     pub upper_left: Point,
@@ -20,6 +32,23 @@ pub struct Aabb {
     pub lower_right: Point,
 }
 
+/// Mirrors the serialized representation of an [`Aabb`]
+/// before its corners have been validated
+#[derive(Deserialize)]
+#[serde(rename = "Aabb")]
+struct UncheckedAabb {
+    upper_left: Point,
+    lower_right: Point,
+}
+
+impl TryFrom<UncheckedAabb> for Aabb {
+    type Error = AabbError;
+
+    fn try_from(unchecked: UncheckedAabb) -> Result<Self, Self::Error> {
+        Aabb::try_new(unchecked.upper_left, unchecked.lower_right)
+    }
+}
+
 impl Aabb {
     /// Creates a new [`Aabb`] from two points.
     ///
@@ -42,10 +71,12 @@ impl Aabb {
     ///
     /// # Errors
     ///
-    /// Returns an error when both points are the same.
+    /// Returns an [`AabbError`] when the upper left point is not strictly
+    /// above and to the left of the lower right point, e.g. when both points are the same.
     ///
     /// [`Aabb`]: ./struct.Aabb.html
-    pub fn try_new<P1, P2>(upper_left: P1, lower_right: P2) -> Result<Self, ()>
+    /// [`AabbError`]: ./enum.AabbError.html
+    pub fn try_new<P1, P2>(upper_left: P1, lower_right: P2) -> Result<Self, AabbError>
     where
         P1: Into<Point>,
         P2: Into<Point>,
@@ -53,8 +84,10 @@ impl Aabb {
         let upper_left = upper_left.into();
         let lower_right = lower_right.into();
 
-        if upper_left.x >= lower_right.x || upper_left.y >= lower_right.y {
-            Err(())
+        if upper_left.x >= lower_right.x {
+            Err(AabbError::NonPositiveWidth)
+        } else if upper_left.y >= lower_right.y {
+            Err(AabbError::NonPositiveHeight)
         } else {
             Ok(Self {
                 upper_left,
@@ -62,6 +95,33 @@ impl Aabb {
             })
         }
     }
+
+    /// Returns the smallest [`Aabb`] that contains this box after rotating it
+    /// by a `rotation` around a `point`. See [`Radians`] for the direction of the rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Aabb, Point, Radians};
+    /// use nearly_eq::assert_nearly_eq;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let aabb = Aabb::try_new((0.0, 0.0), (20.0, 10.0)).unwrap();
+    /// let rotated_aabb = aabb.rotate_around_point(Radians::try_new(FRAC_PI_2).unwrap(), Point::default());
+    ///
+    /// assert_nearly_eq!(0.0, rotated_aabb.upper_left.x);
+    /// assert_nearly_eq!(-20.0, rotated_aabb.upper_left.y);
+    /// assert_nearly_eq!(10.0, rotated_aabb.lower_right.x);
+    /// assert_nearly_eq!(0.0, rotated_aabb.lower_right.y);
+    /// ```
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    pub fn rotate_around_point<R>(&self, rotation: R, point: Point) -> Self
+    where
+        R: Into<Rotation>,
+    {
+        self.transform(Transform::rotation_around_point(rotation, point))
+    }
 }
 
 impl Intersects for Aabb {
@@ -87,15 +147,215 @@ impl Intersects for Aabb {
     }
 }
 
+impl Support for Aabb {
+    fn support_point(&self, direction: Vector) -> Point {
+        Point {
+            x: if direction.x >= 0.0 {
+                self.lower_right.x
+            } else {
+                self.upper_left.x
+            },
+            y: if direction.y >= 0.0 {
+                self.lower_right.y
+            } else {
+                self.upper_left.y
+            },
+        }
+    }
+}
+
+impl Transformable for Aabb {
+    /// Returns the smallest [`Aabb`] that contains the transformed corners of this box
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    fn transform(&self, transform: Transform) -> Self {
+        let corners = [
+            self.upper_left,
+            Point {
+                x: self.lower_right.x,
+                y: self.upper_left.y,
+            },
+            self.lower_right,
+            Point {
+                x: self.upper_left.x,
+                y: self.lower_right.y,
+            },
+        ];
+        let transformed_corners = corners.iter().map(|&corner| transform.map_point(corner));
+        transformed_corners.fold(
+            Aabb {
+                upper_left: Point {
+                    x: f64::INFINITY,
+                    y: f64::INFINITY,
+                },
+                lower_right: Point {
+                    x: f64::NEG_INFINITY,
+                    y: f64::NEG_INFINITY,
+                },
+            },
+            |aabb, corner| Aabb {
+                upper_left: Point {
+                    x: aabb.upper_left.x.min(corner.x),
+                    y: aabb.upper_left.y.min(corner.y),
+                },
+                lower_right: Point {
+                    x: aabb.lower_right.x.max(corner.x),
+                    y: aabb.lower_right.y.max(corner.y),
+                },
+            },
+        )
+    }
+}
+
+impl RayCast for Aabb {
+    /// Casts a ray against this box using the slab method
+    fn cast_ray(&self, ray: &Ray, max_distance: Option<f64>) -> Option<RayHit> {
+        let mut entry_time = f64::NEG_INFINITY;
+        let mut exit_time = f64::INFINITY;
+        let mut entry_normal = Vector::default();
+
+        let slabs = [
+            (
+                ray.origin.x,
+                ray.direction.x,
+                self.upper_left.x,
+                self.lower_right.x,
+                Vector { x: 1.0, y: 0.0 },
+            ),
+            (
+                ray.origin.y,
+                ray.direction.y,
+                self.upper_left.y,
+                self.lower_right.y,
+                Vector { x: 0.0, y: 1.0 },
+            ),
+        ];
+
+        for &(origin, direction, min, max, axis) in &slabs {
+            if direction == 0.0 {
+                // The ray runs parallel to this slab and never enters it
+                // if it doesn't start inside it
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let time_at_min = (min - origin) / direction;
+            let time_at_max = (max - origin) / direction;
+            let (slab_entry_time, slab_exit_time, slab_entry_normal) = if time_at_min < time_at_max
+            {
+                (time_at_min, time_at_max, axis.negative())
+            } else {
+                (time_at_max, time_at_min, axis)
+            };
+
+            if slab_entry_time > entry_time {
+                entry_time = slab_entry_time;
+                entry_normal = slab_entry_normal;
+            }
+            exit_time = exit_time.min(slab_exit_time);
+        }
+
+        if entry_time > exit_time || exit_time < 0.0 {
+            None
+        } else if entry_time < 0.0 {
+            Some(RayHit::at_origin(ray))
+        } else {
+            RayHit::try_new(ray, entry_time, entry_normal, max_distance)
+        }
+    }
+}
+
+/// The reason why an [`Aabb`] instance could not be created
+///
+/// [`Aabb`]: ./struct.Aabb.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AabbError {
+    /// The x coordinate of the upper left point was not smaller
+    /// than the x coordinate of the lower right point
+    NonPositiveWidth,
+    /// The y coordinate of the upper left point was not smaller
+    /// than the y coordinate of the lower right point
+    NonPositiveHeight,
+}
+
+impl fmt::Display for AabbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AabbError::NonPositiveWidth => write!(
+                f,
+                "Upper left x coordinate must be smaller than lower right x coordinate"
+            ),
+            AabbError::NonPositiveHeight => write!(
+                f,
+                "Upper left y coordinate must be smaller than lower right y coordinate"
+            ),
+        }
+    }
+}
+
+impl Error for AabbError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Radians;
+    use nearly_eq::assert_nearly_eq;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn aabb_tokens(upper_left: (f64, f64), lower_right: (f64, f64)) -> Vec<Token> {
+        vec![
+            Token::Struct {
+                name: "Aabb",
+                len: 2,
+            },
+            Token::Str("upper_left"),
+            Token::Struct {
+                name: "Point",
+                len: 2,
+            },
+            Token::Str("x"),
+            Token::F64(upper_left.0),
+            Token::Str("y"),
+            Token::F64(upper_left.1),
+            Token::StructEnd,
+            Token::Str("lower_right"),
+            Token::Struct {
+                name: "Point",
+                len: 2,
+            },
+            Token::Str("x"),
+            Token::F64(lower_right.0),
+            Token::Str("y"),
+            Token::F64(lower_right.1),
+            Token::StructEnd,
+            Token::StructEnd,
+        ]
+    }
 
     #[test]
     fn try_new_errors_for_equal_points() {
         assert!(Aabb::try_new((10.0, 10.0), (10.0, 10.0)).is_err());
     }
 
+    #[test]
+    fn try_new_reports_non_positive_width() {
+        assert_eq!(
+            Err(AabbError::NonPositiveWidth),
+            Aabb::try_new((10.0, 0.0), (10.0, 5.0))
+        );
+    }
+
+    #[test]
+    fn try_new_reports_non_positive_height() {
+        assert_eq!(
+            Err(AabbError::NonPositiveHeight),
+            Aabb::try_new((0.0, 10.0), (5.0, 0.0))
+        );
+    }
+
     #[test]
     fn try_new_errors_when_upper_left_is_larger_than_lower_right() {
         assert!(Aabb::try_new((10.0, 10.0), (0.0, 0.0)).is_err());
@@ -172,4 +432,105 @@ mod tests {
         assert!(!first_aabb.intersects(&second_aabb));
         assert!(!second_aabb.intersects(&first_aabb));
     }
+
+    #[test]
+    fn serializes_and_deserializes_valid_aabb() {
+        let aabb = Aabb::try_new((0.0, 1.0), (10.0, 11.0)).unwrap();
+        assert_tokens(&aabb, &aabb_tokens((0.0, 1.0), (10.0, 11.0)));
+    }
+
+    #[test]
+    fn deserialization_errors_for_inverted_aabb() {
+        assert_de_tokens_error::<Aabb>(
+            &aabb_tokens((10.0, 0.0), (0.0, 10.0)),
+            &AabbError::NonPositiveWidth.to_string(),
+        );
+    }
+
+    #[test]
+    fn ray_hits_left_side() {
+        let aabb = Aabb::try_new((10.0, 0.0), (20.0, 10.0)).unwrap();
+        let ray = Ray::try_new((0.0, 5.0), Vector { x: 1.0, y: 0.0 }).unwrap();
+
+        let hit = aabb.cast_ray(&ray, None).unwrap();
+
+        assert_eq!(10.0, hit.time_of_impact);
+        assert_eq!(Point { x: 10.0, y: 5.0 }, hit.point);
+        assert_eq!(Vector { x: -1.0, y: 0.0 }, hit.normal);
+    }
+
+    #[test]
+    fn ray_hits_upper_side_diagonally() {
+        let aabb = Aabb::try_new((10.0, 10.0), (20.0, 20.0)).unwrap();
+        let ray = Ray::try_new((0.0, 0.0), Vector { x: 1.5, y: 1.0 }).unwrap();
+
+        let hit = aabb.cast_ray(&ray, None).unwrap();
+
+        assert_eq!(10.0, hit.time_of_impact);
+        assert_eq!(Point { x: 15.0, y: 10.0 }, hit.point);
+        assert_eq!(Vector { x: 0.0, y: -1.0 }, hit.normal);
+    }
+
+    #[test]
+    fn ray_misses_when_pointing_away() {
+        let aabb = Aabb::try_new((10.0, 0.0), (20.0, 10.0)).unwrap();
+        let ray = Ray::try_new((0.0, 5.0), Vector { x: -1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, aabb.cast_ray(&ray, None));
+    }
+
+    #[test]
+    fn ray_misses_when_passing_by() {
+        let aabb = Aabb::try_new((10.0, 0.0), (20.0, 10.0)).unwrap();
+        let ray = Ray::try_new((0.0, 11.0), Vector { x: 1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, aabb.cast_ray(&ray, None));
+    }
+
+    #[test]
+    fn ray_misses_when_too_short() {
+        let aabb = Aabb::try_new((10.0, 0.0), (20.0, 10.0)).unwrap();
+        let ray = Ray::try_new((0.0, 5.0), Vector { x: 1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, aabb.cast_ray(&ray, Some(9.0)));
+    }
+
+    #[test]
+    fn rotates_around_point_in_same_direction_as_its_corners() {
+        let aabb = Aabb::try_new((10.0, 0.0), (20.0, 10.0)).unwrap();
+        let rotation = Radians::try_new(FRAC_PI_2).unwrap();
+        let center = Point { x: 10.0, y: 10.0 };
+
+        let rotated_aabb = aabb.rotate_around_point(rotation, center);
+
+        let rotated_corner = Point { x: 20.0, y: 0.0 }.rotate_around_point(rotation, center);
+        assert_nearly_eq!(0.0, rotated_corner.x);
+        assert_nearly_eq!(0.0, rotated_corner.y);
+        assert_nearly_eq!(0.0, rotated_aabb.upper_left.x);
+        assert_nearly_eq!(0.0, rotated_aabb.upper_left.y);
+        assert_nearly_eq!(10.0, rotated_aabb.lower_right.x);
+        assert_nearly_eq!(10.0, rotated_aabb.lower_right.y);
+    }
+
+    #[test]
+    fn rotation_by_pi_keeps_size_of_aabb() {
+        let aabb = Aabb::try_new((10.0, 0.0), (20.0, 10.0)).unwrap();
+
+        let rotated_aabb =
+            aabb.rotate_around_point(Radians::try_new(PI).unwrap(), Point::default());
+
+        assert_nearly_eq!(-20.0, rotated_aabb.upper_left.x);
+        assert_nearly_eq!(-10.0, rotated_aabb.upper_left.y);
+        assert_nearly_eq!(-10.0, rotated_aabb.lower_right.x);
+        assert_nearly_eq!(0.0, rotated_aabb.lower_right.y);
+    }
+
+    #[test]
+    fn ray_starting_inside_hits_immediately() {
+        let aabb = Aabb::try_new((10.0, 0.0), (20.0, 10.0)).unwrap();
+        let ray = Ray::try_new((15.0, 5.0), Vector { x: 2.0, y: 0.0 }).unwrap();
+
+        let hit = aabb.cast_ray(&ray, None).unwrap();
+
+        assert_eq!(0.0, hit.time_of_impact);
+        assert_eq!(Point { x: 15.0, y: 5.0 }, hit.point);
+        assert_eq!(Vector { x: -1.0, y: 0.0 }, hit.normal);
+    }
 }
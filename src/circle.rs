@@ -0,0 +1,434 @@
+use crate::{Aabb, Intersects, Point, Polygon, Ray, RayCast, RayHit, Vector};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+/// A circle
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │         .───.
+/// │       ╱       ╲
+/// │      │    ·────│ ← Radius
+/// │       ╲ Center╱
+/// │         `───´
+/// y
+/// ```
+///
+/// Deserialization goes through [`Circle::try_new`],
+/// so invalid circles are rejected.
+///
+/// [`Circle::try_new`]: ./struct.Circle.html#method.try_new
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedCircle")]
+pub struct Circle {
+    /// The center of the circle
+    pub center: Point,
+    /// The distance from the center to the circle's boundary
+    pub radius: f64,
+}
+
+/// Mirrors the serialized representation of a [`Circle`]
+/// before it has been validated
+#[derive(Deserialize)]
+#[serde(rename = "Circle")]
+struct UncheckedCircle {
+    center: Point,
+    radius: f64,
+}
+
+impl TryFrom<UncheckedCircle> for Circle {
+    type Error = CircleError;
+
+    fn try_from(unchecked: UncheckedCircle) -> Result<Self, Self::Error> {
+        Circle::try_new(unchecked.center, unchecked.radius)
+    }
+}
+
+impl Circle {
+    /// Creates a new [`Circle`] around `center`.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::Circle;
+    ///
+    /// let circle = Circle::try_new((10.0, 10.0), 5.0).expect("Invalid circle");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`CircleError`] if the center is not finite
+    /// or if the radius is not a finite, positive number.
+    ///
+    /// [`Circle`]: ./struct.Circle.html
+    /// [`CircleError`]: ./enum.CircleError.html
+    pub fn try_new<P>(center: P, radius: f64) -> Result<Self, CircleError>
+    where
+        P: Into<Point>,
+    {
+        let center = center.into();
+
+        if !center.x.is_finite() || !center.y.is_finite() {
+            Err(CircleError::NonFiniteCenter)
+        } else if !radius.is_finite() || radius <= 0.0 {
+            Err(CircleError::InvalidRadius)
+        } else {
+            Ok(Self { center, radius })
+        }
+    }
+
+    /// Apply translation specified by `translation`, represented as
+    /// a relative point
+    pub fn translate(&self, translation: Point) -> Self {
+        Self {
+            center: self.center + translation,
+            radius: self.radius,
+        }
+    }
+
+    /// Checks if a given point rests inside the circle
+    pub fn contains_point(&self, point: Point) -> bool {
+        squared_distance(self.center, point) <= self.radius.powi(2)
+    }
+
+    /// Returns an [`Aabb`] which fully contains this circle.
+    ///
+    /// When the center is much further from the origin than the radius,
+    /// the box is slightly larger than the circle, as rounding would otherwise
+    /// collapse it to a width or height of zero.
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    pub fn aabb(&self) -> Aabb {
+        let center = self.center;
+        // Widening the bounds to at least the neighbouring values of the center
+        // keeps them strictly ordered, as Aabb::try_new requires
+        Aabb {
+            upper_left: Point {
+                x: (center.x - self.radius).min(center.x.next_down()),
+                y: (center.y - self.radius).min(center.y.next_down()),
+            },
+            lower_right: Point {
+                x: (center.x + self.radius).max(center.x.next_up()),
+                y: (center.y + self.radius).max(center.y.next_up()),
+            },
+        }
+    }
+}
+
+impl Intersects for Circle {
+    /// Returns wether this circle touches, contains or is contained in another circle
+    fn intersects(&self, other: &Circle) -> bool {
+        squared_distance(self.center, other.center) <= (self.radius + other.radius).powi(2)
+    }
+}
+
+impl Intersects<Aabb> for Circle {
+    /// Returns wether this circle touches, contains or is contained in an [`Aabb`]
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    fn intersects(&self, aabb: &Aabb) -> bool {
+        let closest_point = Point {
+            x: self.center.x.clamp(aabb.upper_left.x, aabb.lower_right.x),
+            y: self.center.y.clamp(aabb.upper_left.y, aabb.lower_right.y),
+        };
+
+        self.contains_point(closest_point)
+    }
+}
+
+impl Intersects<Circle> for Aabb {
+    /// Returns wether this [`Aabb`] touches, contains or is contained in a circle
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    fn intersects(&self, circle: &Circle) -> bool {
+        circle.intersects(self)
+    }
+}
+
+impl Intersects<Polygon> for Circle {
+    /// Returns wether this circle touches, contains or is contained in a [`Polygon`]
+    ///
+    /// [`Polygon`]: ./struct.Polygon.html
+    fn intersects(&self, polygon: &Polygon) -> bool {
+        polygon.contains_point(self.center)
+            || polygon
+                .counter_clockwise_segments()
+                .any(|edge| self.contains_point(edge.closest_point(self.center)))
+    }
+}
+
+impl Intersects<Circle> for Polygon {
+    /// Returns wether this polygon touches, contains or is contained in a circle
+    fn intersects(&self, circle: &Circle) -> bool {
+        circle.intersects(self)
+    }
+}
+
+impl RayCast for Circle {
+    /// Casts a ray against this circle by solving the quadratic equation
+    /// for the points at which the ray is exactly one radius away from the center
+    fn cast_ray(&self, ray: &Ray, max_distance: Option<f64>) -> Option<RayHit> {
+        if self.contains_point(ray.origin) {
+            return Some(RayHit::at_origin(ray));
+        }
+
+        let center_to_origin = Vector::from(ray.origin - self.center);
+        let a = ray.direction.dot_product(ray.direction);
+        let half_b = center_to_origin.dot_product(ray.direction);
+        let c = center_to_origin.dot_product(center_to_origin) - self.radius.powi(2);
+
+        let discriminant = half_b.powi(2) - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let time_of_impact = (-half_b - discriminant.sqrt()) / a;
+        let normal = Vector::from(ray.point_at(time_of_impact) - self.center) / self.radius;
+        RayHit::try_new(ray, time_of_impact, normal, max_distance)
+    }
+}
+
+fn squared_distance(a: Point, b: Point) -> f64 {
+    let delta = Vector::from(b - a);
+    delta.dot_product(delta)
+}
+
+/// The reason why a [`Circle`] instance could not be created
+///
+/// [`Circle`]: ./struct.Circle.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CircleError {
+    /// The center contained a coordinate that was `NaN` or infinite
+    NonFiniteCenter,
+    /// The radius was not a finite, positive number
+    InvalidRadius,
+}
+
+impl fmt::Display for CircleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircleError::NonFiniteCenter => {
+                write!(f, "Center has a coordinate that is NaN or infinite")
+            }
+            CircleError::InvalidRadius => write!(f, "Radius must be finite and positive"),
+        }
+    }
+}
+
+impl Error for CircleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PolygonBuilder;
+    use nearly_eq::assert_nearly_eq;
+
+    fn circle() -> Circle {
+        Circle::try_new((0.0, 0.0), 10.0).unwrap()
+    }
+
+    fn triangle() -> Polygon {
+        PolygonBuilder::default()
+            .vertex(20.0, 0.0)
+            .vertex(30.0, 0.0)
+            .vertex(20.0, 10.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn try_new_errors_for_zero_radius() {
+        assert_eq!(
+            Err(CircleError::InvalidRadius),
+            Circle::try_new((0.0, 0.0), 0.0)
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_negative_radius() {
+        assert_eq!(
+            Err(CircleError::InvalidRadius),
+            Circle::try_new((0.0, 0.0), -1.0)
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_nan_radius() {
+        assert_eq!(
+            Err(CircleError::InvalidRadius),
+            Circle::try_new((0.0, 0.0), f64::NAN)
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_infinite_center() {
+        assert_eq!(
+            Err(CircleError::NonFiniteCenter),
+            Circle::try_new((f64::INFINITY, 0.0), 1.0)
+        );
+    }
+
+    #[test]
+    fn translates() {
+        let translated_circle = circle().translate(Point { x: 3.0, y: -4.0 });
+        assert_eq!(
+            Circle::try_new((3.0, -4.0), 10.0).unwrap(),
+            translated_circle
+        );
+    }
+
+    #[test]
+    fn contains_center() {
+        assert!(circle().contains_point(Point::default()));
+    }
+
+    #[test]
+    fn contains_point_at_border() {
+        assert!(circle().contains_point(Point { x: 6.0, y: -8.0 }));
+    }
+
+    #[test]
+    fn does_not_contain_point_barely_outside() {
+        assert!(!circle().contains_point(Point { x: 7.1, y: 7.1 }));
+    }
+
+    #[test]
+    fn aabb_is_correct() {
+        let circle = Circle::try_new((5.0, -5.0), 2.0).unwrap();
+        let expected_aabb = Aabb::try_new((3.0, -7.0), (7.0, -3.0)).unwrap();
+        assert_eq!(expected_aabb, circle.aabb());
+    }
+
+    #[test]
+    fn intersects_touching_circle() {
+        let other_circle = Circle::try_new((15.0, 0.0), 5.0).unwrap();
+        assert!(circle().intersects(&other_circle));
+        assert!(other_circle.intersects(&circle()));
+    }
+
+    #[test]
+    fn does_not_intersect_circle_when_apart() {
+        let other_circle = Circle::try_new((15.0, 0.0), 4.9).unwrap();
+        assert!(!circle().intersects(&other_circle));
+        assert!(!other_circle.intersects(&circle()));
+    }
+
+    #[test]
+    fn intersects_contained_circle() {
+        let smaller_circle = Circle::try_new((1.0, 1.0), 1.0).unwrap();
+        assert!(circle().intersects(&smaller_circle));
+        assert!(smaller_circle.intersects(&circle()));
+    }
+
+    #[test]
+    fn intersects_overlapping_aabb() {
+        let aabb = Aabb::try_new((5.0, 5.0), (20.0, 20.0)).unwrap();
+        assert!(circle().intersects(&aabb));
+        assert!(aabb.intersects(&circle()));
+    }
+
+    #[test]
+    fn intersects_containing_aabb() {
+        let aabb = Aabb::try_new((-20.0, -20.0), (20.0, 20.0)).unwrap();
+        assert!(circle().intersects(&aabb));
+        assert!(aabb.intersects(&circle()));
+    }
+
+    #[test]
+    fn does_not_intersect_aabb_at_corner() {
+        let aabb = Aabb::try_new((7.5, 7.5), (20.0, 20.0)).unwrap();
+        assert!(!circle().intersects(&aabb));
+        assert!(!aabb.intersects(&circle()));
+    }
+
+    #[test]
+    fn intersects_polygon_overlapping_vertex() {
+        let circle = Circle::try_new((25.0, 15.0), 7.1).unwrap();
+        assert!(circle.intersects(&triangle()));
+        assert!(triangle().intersects(&circle));
+    }
+
+    #[test]
+    fn does_not_intersect_polygon_near_edge() {
+        let circle = Circle::try_new((30.0, 10.0), 3.0).unwrap();
+        assert!(!circle.intersects(&triangle()));
+        assert!(!triangle().intersects(&circle));
+    }
+
+    #[test]
+    fn intersects_polygon_containing_it() {
+        let circle = Circle::try_new((22.0, 2.0), 0.5).unwrap();
+        assert!(circle.intersects(&triangle()));
+        assert!(triangle().intersects(&circle));
+    }
+
+    #[test]
+    fn intersects_polygon_contained_in_it() {
+        let circle = Circle::try_new((22.0, 2.0), 50.0).unwrap();
+        assert!(circle.intersects(&triangle()));
+        assert!(triangle().intersects(&circle));
+    }
+
+    #[test]
+    fn does_not_intersect_polygon_when_apart() {
+        assert!(!circle().intersects(&triangle()));
+        assert!(!triangle().intersects(&circle()));
+    }
+
+    #[test]
+    fn aabb_does_not_collapse_for_large_center() {
+        let circle = Circle::try_new((1e17, 0.0), 1.0).unwrap();
+
+        let aabb = circle.aabb();
+
+        assert_eq!(1e17_f64.next_down(), aabb.upper_left.x);
+        assert_eq!(1e17_f64.next_up(), aabb.lower_right.x);
+        assert_eq!(-1.0, aabb.upper_left.y);
+        assert_eq!(1.0, aabb.lower_right.y);
+        // Deserialization validates through Aabb::try_new
+        assert_eq!(Ok(aabb), Aabb::try_new(aabb.upper_left, aabb.lower_right));
+    }
+
+    #[test]
+    fn ray_hits_circle() {
+        let ray = Ray::try_new((-20.0, 0.0), Vector { x: 2.0, y: 0.0 }).unwrap();
+
+        let hit = circle().cast_ray(&ray, None).unwrap();
+
+        assert_nearly_eq!(5.0, hit.time_of_impact);
+        assert_nearly_eq!(-10.0, hit.point.x);
+        assert_nearly_eq!(0.0, hit.point.y);
+        assert_nearly_eq!(-1.0, hit.normal.x);
+        assert_nearly_eq!(0.0, hit.normal.y);
+    }
+
+    #[test]
+    fn ray_misses_circle_when_passing_by() {
+        let ray = Ray::try_new((-20.0, 10.1), Vector { x: 1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, circle().cast_ray(&ray, None));
+    }
+
+    #[test]
+    fn ray_misses_circle_when_pointing_away() {
+        let ray = Ray::try_new((-20.0, 0.0), Vector { x: -1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, circle().cast_ray(&ray, None));
+    }
+
+    #[test]
+    fn ray_misses_circle_when_too_short() {
+        let ray = Ray::try_new((-20.0, 0.0), Vector { x: 1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, circle().cast_ray(&ray, Some(9.9)));
+    }
+
+    #[test]
+    fn ray_starting_inside_circle_hits_immediately() {
+        let ray = Ray::try_new((1.0, 1.0), Vector { x: 0.0, y: 3.0 }).unwrap();
+
+        let hit = circle().cast_ray(&ray, None).unwrap();
+
+        assert_eq!(0.0, hit.time_of_impact);
+        assert_eq!(Point { x: 1.0, y: 1.0 }, hit.point);
+        assert_eq!(Vector { x: 0.0, y: -1.0 }, hit.normal);
+    }
+}
@@ -0,0 +1,288 @@
+//! Decomposition of simple polygons into convex parts
+
+use crate::{Point, Polygon, SimplePolygon, Vector};
+
+/// Relative tolerance under which a turn between two edges is considered straight
+const COLLINEARITY_TOLERANCE: f64 = 0.000_000_001;
+
+impl SimplePolygon {
+    /// Decomposes the polygon into convex [`Polygon`]s that together cover
+    /// exactly the same area, so that e.g. [`Intersects`] can be used on the parts.
+    ///
+    /// Uses the [Hertel–Mehlhorn] algorithm: The polygon is triangulated, after which
+    /// diagonals are removed for as long as the parts they separate form a convex polygon
+    /// when merged. The result has at most four times as many parts as the optimal decomposition.
+    /// Parts that rounding leaves without an area, i.e. with all of their vertices on a line,
+    /// are left out.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, SimplePolygon};
+    ///
+    /// let l_shape = SimplePolygon::try_new(vec![
+    ///     Point { x: 0.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 5.0 },
+    ///     Point { x: 5.0, y: 5.0 },
+    ///     Point { x: 5.0, y: 10.0 },
+    ///     Point { x: 0.0, y: 10.0 },
+    /// ])
+    /// .unwrap();
+    ///
+    /// let parts = l_shape.convex_decomposition();
+    /// assert_eq!(2, parts.len());
+    /// ```
+    ///
+    /// [`Polygon`]: ./struct.Polygon.html
+    /// [`Intersects`]: ./trait.Intersects.html
+    /// [Hertel–Mehlhorn]: https://en.wikipedia.org/wiki/Polygon_partition#Partition_into_convex_polygons
+    pub fn convex_decomposition(&self) -> Vec<Polygon> {
+        let vertices = self.vertices();
+        let mut parts: Vec<Vec<usize>> = self
+            .triangulate()
+            .into_iter()
+            .map(|triangle| triangle.to_vec())
+            .collect();
+
+        while let Some((first, second, merged_part)) = find_mergeable_parts(vertices, &parts) {
+            parts[first] = merged_part;
+            parts.remove(second);
+        }
+
+        parts
+            .iter()
+            .filter_map(|part| part_to_polygon(vertices, part))
+            .collect()
+    }
+}
+
+/// Finds two parts that share a diagonal and form a convex part when merged
+fn find_mergeable_parts(
+    vertices: &[Point],
+    parts: &[Vec<usize>],
+) -> Option<(usize, usize, Vec<usize>)> {
+    (0..parts.len())
+        .flat_map(|first| (first + 1..parts.len()).map(move |second| (first, second)))
+        .find_map(|(first, second)| {
+            merge_along_shared_edge(&parts[first], &parts[second])
+                // The tolerance of is_convex can accept parts that Polygon::try_new rejects
+                .filter(|merged_part| {
+                    is_convex(vertices, merged_part)
+                        && part_to_polygon(vertices, merged_part).is_some()
+                })
+                .map(|merged_part| (first, second, merged_part))
+        })
+}
+
+/// Merges two counter clockwise parts that share an edge into a single counter clockwise part
+fn merge_along_shared_edge(first: &[usize], second: &[usize]) -> Option<Vec<usize>> {
+    let first_len = first.len();
+    let second_len = second.len();
+
+    (0..first_len).find_map(|first_position| {
+        let start = first[first_position];
+        let end = first[(first_position + 1) % first_len];
+
+        // Both parts are counter clockwise, so the shared edge runs in the opposite direction
+        let second_position = (0..second_len).find(|&second_position| {
+            second[second_position] == end && second[(second_position + 1) % second_len] == start
+        })?;
+
+        let first_vertices =
+            (1..=first_len).map(|offset| first[(first_position + offset) % first_len]);
+        let second_vertices =
+            (2..second_len).map(|offset| second[(second_position + offset) % second_len]);
+        Some(first_vertices.chain(second_vertices).collect())
+    })
+}
+
+/// Returns the cross product of the edges meeting at the `position`th vertex of a part,
+/// together with the tolerance under which the turn is considered straight
+fn turn(vertices: &[Point], part: &[usize], position: usize) -> (f64, f64) {
+    let previous = vertices[part[(position + part.len() - 1) % part.len()]];
+    let current = vertices[part[position]];
+    let next = vertices[part[(position + 1) % part.len()]];
+
+    let incoming = Vector::from(current - previous);
+    let outgoing = Vector::from(next - current);
+    let tolerance = COLLINEARITY_TOLERANCE * incoming.magnitude() * outgoing.magnitude();
+    (incoming.cross_product(outgoing), tolerance)
+}
+
+fn is_convex(vertices: &[Point], part: &[usize]) -> bool {
+    (0..part.len()).all(|position| {
+        let (cross_product, tolerance) = turn(vertices, part, position);
+        cross_product >= -tolerance
+    })
+}
+
+/// Creates a [`Polygon`] from a part, or returns `None` if
+/// the part is not convex or has no area after removing its straight turns
+///
+/// [`Polygon`]: ./struct.Polygon.html
+fn part_to_polygon(vertices: &[Point], part: &[usize]) -> Option<Polygon> {
+    Polygon::try_new(without_straight_turns(vertices, part)).ok()
+}
+
+fn without_straight_turns(vertices: &[Point], part: &[usize]) -> Vec<Point> {
+    (0..part.len())
+        .filter(|&position| {
+            let (cross_product, tolerance) = turn(vertices, part, position);
+            cross_product.abs() > tolerance
+        })
+        .map(|position| vertices[part[position]])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Intersects;
+    use nearly_eq::assert_nearly_eq;
+
+    fn area(polygon: &Polygon) -> f64 {
+        SimplePolygon::from(polygon.clone()).area()
+    }
+
+    fn assert_decomposition_covers(simple_polygon: &SimplePolygon, parts: &[Polygon]) {
+        let total_area: f64 = parts.iter().map(area).sum();
+        assert_nearly_eq!(simple_polygon.area(), total_area, 0.000_001);
+
+        for part in parts {
+            for &vertex in part.vertices() {
+                assert!(simple_polygon.contains_point(vertex));
+            }
+        }
+    }
+
+    #[test]
+    fn convex_polygon_is_its_own_decomposition() {
+        let simple_polygon = SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 12.0, y: 5.0 },
+            Point { x: 5.0, y: 10.0 },
+            Point { x: -2.0, y: 5.0 },
+        ])
+        .unwrap();
+
+        let parts = simple_polygon.convex_decomposition();
+
+        assert_eq!(1, parts.len());
+        assert_decomposition_covers(&simple_polygon, &parts);
+    }
+
+    #[test]
+    fn l_shape_is_decomposed_into_two_parts() {
+        let simple_polygon = SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 5.0 },
+            Point { x: 5.0, y: 5.0 },
+            Point { x: 5.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap();
+
+        let parts = simple_polygon.convex_decomposition();
+
+        assert_eq!(2, parts.len());
+        assert_decomposition_covers(&simple_polygon, &parts);
+    }
+
+    #[test]
+    fn clockwise_comb_is_decomposed() {
+        let simple_polygon = SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+            Point { x: 2.0, y: 10.0 },
+            Point { x: 2.0, y: 2.0 },
+            Point { x: 4.0, y: 2.0 },
+            Point { x: 4.0, y: 10.0 },
+            Point { x: 6.0, y: 10.0 },
+            Point { x: 6.0, y: 2.0 },
+            Point { x: 8.0, y: 2.0 },
+            Point { x: 8.0, y: 10.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 10.0, y: 0.0 },
+        ])
+        .unwrap();
+
+        let parts = simple_polygon.convex_decomposition();
+
+        assert!(parts.len() >= 4);
+        assert_decomposition_covers(&simple_polygon, &parts);
+    }
+
+    #[test]
+    fn collinear_vertices_are_removed_from_parts() {
+        let simple_polygon = SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 5.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap();
+
+        let parts = simple_polygon.convex_decomposition();
+
+        assert_eq!(1, parts.len());
+        assert_eq!(4, parts[0].vertices().len());
+    }
+
+    #[test]
+    fn parts_can_be_used_for_intersection_tests() {
+        let simple_polygon = SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 5.0, y: 2.0 },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap();
+        let probe_in_notch = Polygon::try_new(vec![
+            Point { x: 4.0, y: 8.0 },
+            Point { x: 6.0, y: 8.0 },
+            Point { x: 5.0, y: 9.0 },
+        ])
+        .unwrap();
+        let probe_in_body = probe_in_notch.translate(Point { x: 0.0, y: -7.0 });
+
+        let parts = simple_polygon.convex_decomposition();
+
+        assert_decomposition_covers(&simple_polygon, &parts);
+        assert!(!parts.iter().any(|part| part.intersects(&probe_in_notch)));
+        assert!(parts.iter().any(|part| part.intersects(&probe_in_body)));
+    }
+
+    #[test]
+    fn nearly_straight_dent_is_decomposed_without_panicking() {
+        let simple_polygon = SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point {
+                x: 5.0,
+                y: 10.0 - 0.000_000_000_001,
+            },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap();
+
+        let parts = simple_polygon.convex_decomposition();
+
+        assert_decomposition_covers(&simple_polygon, &parts);
+    }
+
+    #[test]
+    fn part_without_area_is_skipped() {
+        let vertices = [
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 5.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+        ];
+
+        assert_eq!(None, part_to_polygon(&vertices, &[0, 1, 2]));
+    }
+}
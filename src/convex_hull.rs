@@ -1,6 +1,15 @@
-use crate::Point;
+use crate::{Point, Polygon, Vector};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::vec;
 
-/// Finds the [Convex Hull] for a given set of [`Point`]s in counter clockwise order.
+/// Finds the [Convex Hull] for a given set of [`Point`]s in counter clockwise order,
+/// as defined by [`Winding`].
+///
+/// The hull starts at the leftmost point (the lowest one, if several points share
+/// the smallest x coordinate). Repeated points are only reported once.
 ///
 /// ## Examples
 ///
@@ -27,93 +36,259 @@ use crate::Point;
 ///
 /// [Convex Hull]: http://jeffe.cs.illinois.edu/teaching/373/notes/x05-convexhull.pdf
 /// [`Point`]: ./struct.Point.html
-#[derive(Debug)]
-pub struct ConvexHull<'a> {
-    points: &'a [Point],
-    leftmost_point: Point,
-    current_point: Point,
-    state: ConvexHullState,
+/// [`Winding`]: ./enum.Winding.html
+#[derive(Debug, Clone)]
+pub struct ConvexHull {
+    vertices: vec::IntoIter<Point>,
 }
 
-#[derive(Debug)]
-enum ConvexHullState {
-    Initial,
-    FindingNextPoint,
+/// Whether points that lie on an edge of a [`ConvexHull`] between
+/// two of its corners are reported as part of the hull
+///
+/// [`ConvexHull`]: ./struct.ConvexHull.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CollinearPoints {
+    /// Only report the corners of the hull
+    Discard,
+    /// Report every point on the boundary of the hull
+    Keep,
 }
 
-impl<'a> ConvexHull<'a> {
+impl ConvexHull {
     /// Constructs a new [`ConvexHull`] from a given set of points.
+    /// Points on the hull's edges that are not corners are discarded.
+    ///
+    /// Runs in O(n log n) time.
     ///
     /// ## Errors
-    /// Returns an error when zero points are given.
+    /// Returns [`ConvexHullError::NoPoints`] when zero points are given
+    /// and [`ConvexHullError::NonFinitePoint`] when a point is `NaN` or infinite.
     ///
     /// [`ConvexHull`]: ./struct.ConvexHull.html
-    pub fn try_new(points: &'a [Point]) -> Result<Self, ()> {
+    /// [`ConvexHullError::NoPoints`]: ./enum.ConvexHullError.html#variant.NoPoints
+    /// [`ConvexHullError::NonFinitePoint`]: ./enum.ConvexHullError.html#variant.NonFinitePoint
+    pub fn try_new<I>(points: I) -> Result<Self, ConvexHullError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Point>,
+    {
+        Self::try_with_collinear_points(points, CollinearPoints::Discard)
+    }
+
+    /// Constructs a new [`ConvexHull`] from a given set of points,
+    /// treating points on the hull's edges according to `collinear_points`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use myelin_geometry::{CollinearPoints, ConvexHull, Point};
+    ///
+    /// let points = [
+    ///     Point { x: 0.0, y: 0.0 },
+    ///     Point { x: 5.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 0.0 },
+    ///     Point { x: 5.0, y: 5.0 },
+    /// ];
+    ///
+    /// let corners = ConvexHull::try_with_collinear_points(&points, CollinearPoints::Discard).unwrap();
+    /// assert_eq!(3, corners.count());
+    ///
+    /// let boundary = ConvexHull::try_with_collinear_points(&points, CollinearPoints::Keep).unwrap();
+    /// assert_eq!(4, boundary.count());
+    /// ```
+    ///
+    /// ## Errors
+    /// See [`ConvexHull::try_new`].
+    ///
+    /// [`ConvexHull`]: ./struct.ConvexHull.html
+    /// [`ConvexHull::try_new`]: ./struct.ConvexHull.html#method.try_new
+    pub fn try_with_collinear_points<I>(
+        points: I,
+        collinear_points: CollinearPoints,
+    ) -> Result<Self, ConvexHullError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Point>,
+    {
+        let mut points: Vec<Point> = points.into_iter().map(|point| *point.borrow()).collect();
+
         if points.is_empty() {
-            Err(())
-        } else {
-            // Safe unwrap: Points should not be baloney like NaN
-            let leftmost_point = *points
-                .iter()
-                .min_by(|a, b| a.partial_cmp(&b).unwrap())
-                .expect("At least one point must be given");
-            Ok(Self {
-                points,
-                leftmost_point,
-                current_point: leftmost_point,
-                state: ConvexHullState::Initial,
-            })
+            return Err(ConvexHullError::NoPoints);
         }
+
+        if let Some(index) = points
+            .iter()
+            .position(|point| !point.x.is_finite() || !point.y.is_finite())
+        {
+            return Err(ConvexHullError::NonFinitePoint { index });
+        }
+
+        // All points are finite, so they are totally ordered
+        points.sort_by(|first, second| first.partial_cmp(second).unwrap_or(Ordering::Equal));
+        points.dedup();
+
+        Ok(Self {
+            vertices: monotone_chain(&points, collinear_points).into_iter(),
+        })
+    }
+
+    /// Collects the remaining vertices of the hull into a [`Polygon`].
+    /// Points on the hull's edges that are not corners are left out.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use myelin_geometry::{ConvexHull, Point};
+    ///
+    /// let points = vec![
+    ///     Point { x: 0.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 0.0 },
+    ///     Point { x: 5.0, y: 2.0 },
+    ///     Point { x: 5.0, y: 10.0 },
+    /// ];
+    ///
+    /// let polygon = ConvexHull::try_new(points).unwrap().into_polygon().unwrap();
+    /// assert_eq!(3, polygon.vertices().len());
+    /// ```
+    ///
+    /// ## Errors
+    /// Returns [`ConvexHullError::Degenerate`] if the hull doesn't enclose any area,
+    /// i.e. when all points are identical or lie on a single line.
+    ///
+    /// [`Polygon`]: ./struct.Polygon.html
+    /// [`ConvexHullError::Degenerate`]: ./enum.ConvexHullError.html#variant.Degenerate
+    pub fn into_polygon(self) -> Result<Polygon, ConvexHullError> {
+        let vertices: Vec<_> = self.vertices.collect();
+        let corners = match ConvexHull::try_new(&vertices) {
+            Ok(convex_hull) => convex_hull.collect(),
+            Err(_) => Vec::new(),
+        };
+        Polygon::try_new(corners).map_err(|_| ConvexHullError::Degenerate {
+            count: vertices.len(),
+        })
     }
 }
 
-impl<'a> Iterator for ConvexHull<'a> {
+impl Iterator for ConvexHull {
     type Item = Point;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.state {
-            ConvexHullState::Initial => {
-                self.state = ConvexHullState::FindingNextPoint;
-                Some(self.leftmost_point)
-            }
-            ConvexHullState::FindingNextPoint => self.find_next_point(),
+        self.vertices.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.vertices.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ConvexHull {}
+
+/// Implementation of [Andrew's monotone chain algorithm] for sorted, unique points
+///
+/// [Andrew's monotone chain algorithm]: https://en.wikibooks.org/wiki/Algorithm_Implementation/Geometry/Convex_hull/Monotone_chain
+fn monotone_chain(sorted_points: &[Point], collinear_points: CollinearPoints) -> Vec<Point> {
+    if sorted_points.len() < 3 {
+        return sorted_points.to_vec();
+    }
+
+    let first = sorted_points[0];
+    let last = sorted_points[sorted_points.len() - 1];
+    let all_collinear = sorted_points
+        .iter()
+        .all(|&point| cross_product(first, last, point) == 0.0);
+    if all_collinear {
+        return match collinear_points {
+            CollinearPoints::Discard => vec![first, last],
+            CollinearPoints::Keep => sorted_points.to_vec(),
+        };
+    }
+
+    let mut hull = half_hull(sorted_points.iter(), collinear_points);
+    // The last point of each half is the first point of the other half
+    hull.pop();
+    let mut upper_hull = half_hull(sorted_points.iter().rev(), collinear_points);
+    upper_hull.pop();
+    hull.extend(upper_hull);
+    hull
+}
+
+/// Builds the part of the hull that is passed while walking counter clockwise
+/// from the first to the last of the sorted `points`
+pub(crate) fn half_hull<'a>(
+    points: impl Iterator<Item = &'a Point>,
+    collinear_points: CollinearPoints,
+) -> Vec<Point> {
+    let is_removed_by = |turn: f64| match collinear_points {
+        CollinearPoints::Discard => turn <= 0.0,
+        CollinearPoints::Keep => turn < 0.0,
+    };
+
+    let mut chain: Vec<Point> = Vec::new();
+    for &point in points {
+        while chain.len() >= 2
+            && is_removed_by(cross_product(
+                chain[chain.len() - 2],
+                chain[chain.len() - 1],
+                point,
+            ))
+        {
+            chain.pop();
         }
+        chain.push(point);
     }
+    chain
 }
 
-impl<'a> ConvexHull<'a> {
-    /// Implementation of [Jarvis March]
+/// Returns the cross product of `first` → `second` and `first` → `third`,
+/// which is positive for a counter clockwise turn
+pub(crate) fn cross_product(first: Point, second: Point, third: Point) -> f64 {
+    Vector::from(second - first).cross_product(Vector::from(third - first))
+}
+
+/// The reason why a [`ConvexHull`] could not be constructed
+///
+/// [`ConvexHull`]: ./struct.ConvexHull.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ConvexHullError {
+    /// No points were given
+    NoPoints,
+    /// A point contained a coordinate that was `NaN` or infinite
+    NonFinitePoint {
+        /// The index of the offending point
+        index: usize,
+    },
+    /// The hull doesn't enclose any area and can thus not be turned into a [`Polygon`]
     ///
-    /// [Jarvis March]: https://www.algorithm-archive.org/contents/jarvis_march/jarvis_march.html
-    fn find_next_point(&mut self) -> Option<Point> {
-        let first_point = *self.points.first().unwrap();
+    /// [`Polygon`]: ./struct.Polygon.html
+    Degenerate {
+        /// The number of vertices the hull had
+        count: usize,
+    },
+}
 
-        self.current_point = self
-            .points
-            .iter()
-            .skip(1)
-            .fold(first_point, |endpoint, &point| {
-                if endpoint == self.current_point
-                    || !is_counter_clockwise_turn(point, self.current_point, endpoint)
-                {
-                    point
-                } else {
-                    endpoint
-                }
-            });
-
-        if self.leftmost_point == self.current_point {
-            None
-        } else {
-            Some(self.current_point)
+impl fmt::Display for ConvexHullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvexHullError::NoPoints => {
+                write!(f, "At least one point is required to build a convex hull")
+            }
+            ConvexHullError::NonFinitePoint { index } => {
+                write!(
+                    f,
+                    "Point {} has a coordinate that is NaN or infinite",
+                    index
+                )
+            }
+            ConvexHullError::Degenerate { count } => write!(
+                f,
+                "Convex hull with {} vertices does not enclose any area",
+                count
+            ),
         }
     }
 }
 
-/// Source: <http://jeffe.cs.illinois.edu/teaching/373/notes/x05-convexhull.pdf> (Page 2)
-fn is_counter_clockwise_turn(p1: Point, p2: Point, p3: Point) -> bool {
-    (p3.y - p1.y) * (p2.x - p1.x) >= (p2.y - p1.y) * (p3.x - p1.x)
-}
+impl Error for ConvexHullError {}
 
 #[cfg(test)]
 mod tests {
@@ -121,7 +296,10 @@ mod tests {
 
     #[test]
     fn constructor_fails_with_zero_points() {
-        assert!(ConvexHull::try_new(&[]).is_err());
+        assert_eq!(
+            ConvexHullError::NoPoints,
+            ConvexHull::try_new(Vec::<Point>::new()).unwrap_err()
+        );
     }
 
     #[test]
@@ -181,4 +359,121 @@ mod tests {
 
         assert_eq!(expected_hull, hull);
     }
+
+    #[test]
+    fn constructor_fails_with_nan_point() {
+        let points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point {
+                x: f64::NAN,
+                y: 1.0,
+            },
+        ];
+
+        assert_eq!(
+            ConvexHullError::NonFinitePoint { index: 1 },
+            ConvexHull::try_new(points).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn convex_hull_reports_duplicates_once() {
+        let points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+            Point { x: 10.0, y: 0.0 },
+        ];
+
+        let expected_hull = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+        ];
+
+        let hull: Vec<_> = ConvexHull::try_new(points).unwrap().collect();
+
+        assert_eq!(expected_hull, hull);
+    }
+
+    #[test]
+    fn convex_hull_keeps_collinear_points_on_every_edge() {
+        let points = vec![
+            Point { x: 5.0, y: 5.0 },
+            Point { x: 0.0, y: 5.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 5.0 },
+            Point { x: 5.0, y: 10.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+            Point { x: 5.0, y: 0.0 },
+        ];
+
+        let expected_hull = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 5.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 5.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 5.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+            Point { x: 0.0, y: 5.0 },
+        ];
+
+        let hull: Vec<_> = ConvexHull::try_with_collinear_points(&points, CollinearPoints::Keep)
+            .unwrap()
+            .collect();
+
+        assert_eq!(expected_hull, hull);
+    }
+
+    #[test]
+    fn convex_hull_of_collinear_points_is_a_line() {
+        let points = vec![
+            Point { x: 2.0, y: 2.0 },
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+        ];
+
+        let corners: Vec<_> = ConvexHull::try_new(&points).unwrap().collect();
+        let boundary: Vec<_> =
+            ConvexHull::try_with_collinear_points(&points, CollinearPoints::Keep)
+                .unwrap()
+                .collect();
+
+        assert_eq!(vec![points[1], points[0]], corners);
+        assert_eq!(vec![points[1], points[2], points[0]], boundary);
+    }
+
+    #[test]
+    fn into_polygon_leaves_out_collinear_points() {
+        let points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 5.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+        ];
+
+        let polygon = ConvexHull::try_with_collinear_points(&points, CollinearPoints::Keep)
+            .unwrap()
+            .into_polygon()
+            .unwrap();
+
+        assert_eq!(&[points[0], points[2], points[3]], polygon.vertices());
+    }
+
+    #[test]
+    fn into_polygon_fails_for_collinear_points() {
+        let points = vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 1.0 }];
+
+        assert_eq!(
+            ConvexHullError::Degenerate { count: 2 },
+            ConvexHull::try_new(points)
+                .unwrap()
+                .into_polygon()
+                .unwrap_err()
+        );
+    }
 }
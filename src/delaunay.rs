@@ -0,0 +1,796 @@
+//! Delaunay triangulations of point sets
+
+use crate::triangulation::ear_clip;
+use crate::{Point, Segment, Vector};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+
+/// A [Delaunay triangulation] of a set of points, optionally constrained
+/// to contain specific edges.
+///
+/// No point lies inside the circumcircle of any triangle, except for points
+/// hidden behind a constrained edge. This maximizes the minimum angle of all
+/// triangles and makes the edges a natural neighbourhood graph over the points.
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │   ·───────────·
+/// │   │ ╲       ╱ │
+/// │   │   ╲   ╱   │
+/// │   │     ·     │
+/// │   │   ╱   ╲   │
+/// │   │ ╱       ╲ │
+/// │   ·───────────·
+/// y
+/// ```
+///
+/// [Delaunay triangulation]: https://en.wikipedia.org/wiki/Delaunay_triangulation
+#[derive(Debug, PartialEq, Clone)]
+pub struct DelaunayTriangulation {
+    points: Vec<Point>,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl DelaunayTriangulation {
+    /// Triangulates `points` using the [Bowyer–Watson algorithm].
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{DelaunayTriangulation, Point};
+    ///
+    /// let points = [
+    ///     Point { x: 0.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 10.0 },
+    ///     Point { x: 0.0, y: 10.0 },
+    ///     Point { x: 5.0, y: 5.0 },
+    /// ];
+    ///
+    /// let triangulation = DelaunayTriangulation::try_new(&points).expect("Invalid points");
+    /// assert_eq!(4, triangulation.triangles().len());
+    /// assert_eq!(vec![0, 1, 2, 3], triangulation.adjacency()[4]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`DelaunayError`] if fewer than three points are given,
+    /// if any point is `NaN` or infinite, if a point is repeated
+    /// or if all points lie on a single line.
+    ///
+    /// [Bowyer–Watson algorithm]: https://en.wikipedia.org/wiki/Bowyer%E2%80%93Watson_algorithm
+    /// [`DelaunayError`]: ./enum.DelaunayError.html
+    pub fn try_new(points: &[Point]) -> Result<Self, DelaunayError> {
+        Self::try_with_constraints(points, &[])
+    }
+
+    /// Triangulates `points` so that every pair of point indices in
+    /// `constrained_edges` is connected by an edge. Triangles that would
+    /// cross a constrained edge are replaced by a [constrained Delaunay triangulation].
+    ///
+    /// This can be used to preserve the boundary of a [`Polygon`] by passing
+    /// its vertices together with the edges between consecutive vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{DelaunayTriangulation, Point};
+    ///
+    /// let points = [
+    ///     Point { x: 0.0, y: 0.0 },
+    ///     Point { x: 5.0, y: -1.0 },
+    ///     Point { x: 10.0, y: 0.0 },
+    ///     Point { x: 5.0, y: 1.0 },
+    /// ];
+    ///
+    /// let triangulation = DelaunayTriangulation::try_with_constraints(&points, &[(0, 2)])
+    ///     .expect("Invalid points or constraints");
+    /// assert!(triangulation.edges().contains(&(0, 2)));
+    /// assert!(!triangulation.edges().contains(&(1, 3)));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`DelaunayError`] for the same reasons as [`DelaunayTriangulation::try_new`].
+    /// Additionally, constrained edges must connect two different, existing points,
+    /// must not pass through any other point and must not cross each other.
+    ///
+    /// [constrained Delaunay triangulation]: https://en.wikipedia.org/wiki/Constrained_Delaunay_triangulation
+    /// [`Polygon`]: ./struct.Polygon.html
+    /// [`DelaunayError`]: ./enum.DelaunayError.html
+    /// [`DelaunayTriangulation::try_new`]: ./struct.DelaunayTriangulation.html#method.try_new
+    pub fn try_with_constraints(
+        points: &[Point],
+        constrained_edges: &[(usize, usize)],
+    ) -> Result<Self, DelaunayError> {
+        validate_points(points)?;
+        validate_constraints(points, constrained_edges)?;
+
+        let mut triangles = bowyer_watson(points);
+        fill_hull_pockets(points, &mut triangles);
+        for &constrained_edge in constrained_edges {
+            insert_constrained_edge(points, &mut triangles, constrained_edge);
+        }
+        let constrained_edges = constrained_edges
+            .iter()
+            .map(|&(first, second)| undirected_edge(first, second))
+            .collect();
+        legalize(points, &mut triangles, &constrained_edges);
+
+        Ok(Self {
+            points: points.to_vec(),
+            triangles,
+        })
+    }
+
+    /// Returns the triangulated points
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Returns the triangles as counter clockwise triples of indices into [`DelaunayTriangulation::points`]
+    ///
+    /// [`DelaunayTriangulation::points`]: ./struct.DelaunayTriangulation.html#method.points
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Returns every edge of the triangulation exactly once, as a pair of point indices
+    /// with the smaller index first. The edges are sorted.
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        let mut edges: Vec<_> = self
+            .triangles
+            .iter()
+            .flat_map(|&triangle| triangle_edges(triangle))
+            .map(|(start, end)| undirected_edge(start, end))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+
+    /// Returns the sorted indices of the points connected to each point by an edge,
+    /// indexed like [`DelaunayTriangulation::points`]
+    ///
+    /// [`DelaunayTriangulation::points`]: ./struct.DelaunayTriangulation.html#method.points
+    pub fn adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![Vec::new(); self.points.len()];
+        for (first, second) in self.edges() {
+            adjacency[first].push(second);
+            adjacency[second].push(first);
+        }
+        for neighbours in &mut adjacency {
+            neighbours.sort_unstable();
+        }
+        adjacency
+    }
+}
+
+fn validate_points(points: &[Point]) -> Result<(), DelaunayError> {
+    const MINIMUM_POINTS_FOR_A_TRIANGLE: usize = 3;
+
+    if points.len() < MINIMUM_POINTS_FOR_A_TRIANGLE {
+        return Err(DelaunayError::TooFewPoints {
+            count: points.len(),
+        });
+    }
+
+    if let Some(index) = points
+        .iter()
+        .position(|point| !point.x.is_finite() || !point.y.is_finite())
+    {
+        return Err(DelaunayError::NonFinitePoint { index });
+    }
+
+    let mut sorted_indices: Vec<_> = (0..points.len()).collect();
+    sorted_indices.sort_unstable_by(|&first, &second| {
+        points[first]
+            .partial_cmp(&points[second])
+            .expect("Points are finite")
+            .then(first.cmp(&second))
+    });
+    if let Some(index) = sorted_indices
+        .windows(2)
+        .filter(|pair| points[pair[0]] == points[pair[1]])
+        .map(|pair| pair[1])
+        .min()
+    {
+        return Err(DelaunayError::DuplicatePoint { index });
+    }
+
+    let first = points[0];
+    let second = points[1];
+    let all_collinear = points[2..]
+        .iter()
+        .all(|&point| orientation(first, second, point) == 0.0);
+    if all_collinear {
+        return Err(DelaunayError::CollinearPoints);
+    }
+
+    Ok(())
+}
+
+fn validate_constraints(
+    points: &[Point],
+    constrained_edges: &[(usize, usize)],
+) -> Result<(), DelaunayError> {
+    let is_invalid = |&(start, end): &(usize, usize)| {
+        if start >= points.len() || end >= points.len() || start == end {
+            return true;
+        }
+        let segment = Segment {
+            start: points[start],
+            end: points[end],
+        };
+        points
+            .iter()
+            .enumerate()
+            .any(|(index, &point)| index != start && index != end && segment.contains_point(point))
+    };
+    if let Some(index) = constrained_edges.iter().position(is_invalid) {
+        return Err(DelaunayError::InvalidConstraint { index });
+    }
+
+    let crossing_constraints = (0..constrained_edges.len())
+        .flat_map(|first| (first + 1..constrained_edges.len()).map(move |second| (first, second)))
+        .find(|&(first, second)| {
+            let (first_start, first_end) = constrained_edges[first];
+            let (second_start, second_end) = constrained_edges[second];
+            crosses(
+                (points[first_start], points[first_end]),
+                (points[second_start], points[second_end]),
+            )
+        });
+    if let Some((first, second)) = crossing_constraints {
+        return Err(DelaunayError::CrossingConstraints { first, second });
+    }
+
+    Ok(())
+}
+
+/// Inserts the points one by one into a triangle that encloses all of them,
+/// each time replacing the triangles whose circumcircle contains the new point
+fn bowyer_watson(points: &[Point]) -> Vec<[usize; 3]> {
+    // Chosen large enough that the super triangle's vertices rarely influence the result.
+    // Concavities they cause along the convex hull are filled in afterwards.
+    const SUPER_TRIANGLE_SCALE: f64 = 100.0;
+
+    let (min_x, max_x, min_y, max_y) = points.iter().fold(
+        (
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ),
+        |(min_x, max_x, min_y, max_y), point| {
+            (
+                min_x.min(point.x),
+                max_x.max(point.x),
+                min_y.min(point.y),
+                max_y.max(point.y),
+            )
+        },
+    );
+    let size = (max_x - min_x).max(max_y - min_y) * SUPER_TRIANGLE_SCALE;
+    let center = Point {
+        x: (min_x + max_x) / 2.0,
+        y: (min_y + max_y) / 2.0,
+    };
+
+    let mut all_points = points.to_vec();
+    all_points.push(Point {
+        x: center.x - size,
+        y: center.y - size,
+    });
+    all_points.push(Point {
+        x: center.x + size,
+        y: center.y - size,
+    });
+    all_points.push(Point {
+        x: center.x,
+        y: center.y + size,
+    });
+
+    let super_triangle = [points.len(), points.len() + 1, points.len() + 2];
+    let mut triangles = vec![super_triangle];
+    for (index, &point) in points.iter().enumerate() {
+        let (invalid_triangles, valid_triangles): (Vec<_>, Vec<_>) =
+            triangles.into_iter().partition(|&[first, second, third]| {
+                in_circumcircle(
+                    [all_points[first], all_points[second], all_points[third]],
+                    point,
+                ) > 0.0
+            });
+
+        triangles = valid_triangles;
+        triangles
+            .extend(boundary_edges(&invalid_triangles).map(|(start, end)| [start, end, index]));
+    }
+
+    triangles.retain(|triangle| triangle.iter().all(|&index| index < points.len()));
+    triangles
+}
+
+/// Adds triangles along the boundary until it is convex. The super triangle
+/// of [`bowyer_watson`] can leave such concavities along the convex hull.
+fn fill_hull_pockets(points: &[Point], triangles: &mut Vec<[usize; 3]>) {
+    let next_on_boundary: HashMap<_, _> = boundary_edges(triangles).collect();
+    let start = match next_on_boundary.keys().next() {
+        Some(&start) => start,
+        None => return,
+    };
+
+    let mut boundary = vec![start];
+    let mut current = next_on_boundary[&start];
+    while current != start && boundary.len() < next_on_boundary.len() {
+        boundary.push(current);
+        current = next_on_boundary[&current];
+    }
+
+    let turn = |boundary: &[usize], position: usize| {
+        let previous = boundary[(position + boundary.len() - 1) % boundary.len()];
+        let next = boundary[(position + 1) % boundary.len()];
+        (
+            previous,
+            orientation(points[previous], points[boundary[position]], points[next]),
+            next,
+        )
+    };
+    while boundary.len() > 3 {
+        let concave_position =
+            (0..boundary.len()).find(|&position| turn(&boundary, position).1 < 0.0);
+        match concave_position {
+            Some(position) => {
+                let (previous, _, next) = turn(&boundary, position);
+                triangles.push([previous, next, boundary[position]]);
+                boundary.remove(position);
+            }
+            None => break,
+        }
+    }
+}
+
+/// Removes all triangles crossed by the edge between `start` and `end`
+/// and triangulates the cavities on either side of it
+fn insert_constrained_edge(
+    points: &[Point],
+    triangles: &mut Vec<[usize; 3]>,
+    (start, end): (usize, usize),
+) {
+    let edge = (points[start], points[end]);
+    let (crossed_triangles, remaining_triangles): (Vec<_>, Vec<_>) =
+        triangles.iter().partition(|&&triangle| {
+            triangle_edges(triangle)
+                .any(|(first, second)| crosses(edge, (points[first], points[second])))
+        });
+    if crossed_triangles.is_empty() {
+        return;
+    }
+
+    let next_on_boundary: HashMap<_, _> = boundary_edges(&crossed_triangles).collect();
+    let chain_between = |from: usize, to: usize| {
+        let mut chain = vec![from];
+        let mut current = from;
+        while current != to && chain.len() <= next_on_boundary.len() {
+            current = next_on_boundary[&current];
+            chain.push(current);
+        }
+        chain
+    };
+
+    *triangles = remaining_triangles;
+    for cavity in &[chain_between(start, end), chain_between(end, start)] {
+        let cavity_points: Vec<_> = cavity.iter().map(|&index| points[index]).collect();
+        triangles.extend(ear_clip(&cavity_points).into_iter().map(|triangle| {
+            [
+                cavity[triangle[0]],
+                cavity[triangle[1]],
+                cavity[triangle[2]],
+            ]
+        }));
+    }
+}
+
+/// Flips edges until every edge that is not constrained is locally Delaunay,
+/// i.e. the opposite vertex of the neighbouring triangle lies outside
+/// the circumcircle of each triangle. See [Lawson's flip algorithm].
+///
+/// [Lawson's flip algorithm]: https://en.wikipedia.org/wiki/Delaunay_triangulation#Flip_algorithms
+fn legalize(
+    points: &[Point],
+    triangles: &mut [[usize; 3]],
+    constrained_edges: &HashSet<(usize, usize)>,
+) {
+    // Tolerates rounding errors for cocircular points, which would otherwise be flipped back and forth
+    const COCIRCULARITY_TOLERANCE: f64 = 0.000_000_001;
+
+    let mut owners: HashMap<(usize, usize), usize> = HashMap::new();
+    for (index, &triangle) in triangles.iter().enumerate() {
+        owners.extend(triangle_edges(triangle).map(|edge| (edge, index)));
+    }
+
+    let mut unchecked_edges: Vec<_> = owners.keys().copied().collect();
+    while let Some((start, end)) = unchecked_edges.pop() {
+        if constrained_edges.contains(&undirected_edge(start, end)) {
+            continue;
+        }
+        let (first, second) = match (owners.get(&(start, end)), owners.get(&(end, start))) {
+            (Some(&first), Some(&second)) => (first, second),
+            _ => continue,
+        };
+        let first_opposite = opposite_vertex(triangles[first], start, end);
+        let second_opposite = opposite_vertex(triangles[second], start, end);
+
+        let triangle = [points[start], points[end], points[first_opposite]];
+        let scale = Vector::from(points[end] - points[start])
+            .magnitude()
+            .max(Vector::from(points[second_opposite] - points[first_opposite]).magnitude());
+        let tolerance = COCIRCULARITY_TOLERANCE * scale.powi(4);
+        if in_circumcircle(triangle, points[second_opposite]) <= tolerance {
+            continue;
+        }
+
+        for &index in &[first, second] {
+            for edge in triangle_edges(triangles[index]) {
+                owners.remove(&edge);
+            }
+        }
+        triangles[first] = [first_opposite, start, second_opposite];
+        triangles[second] = [second_opposite, end, first_opposite];
+        for &index in &[first, second] {
+            owners.extend(triangle_edges(triangles[index]).map(|edge| (edge, index)));
+        }
+        unchecked_edges.extend(&[
+            (start, second_opposite),
+            (second_opposite, end),
+            (end, first_opposite),
+            (first_opposite, start),
+        ]);
+    }
+}
+
+fn triangle_edges([first, second, third]: [usize; 3]) -> impl Iterator<Item = (usize, usize)> {
+    vec![(first, second), (second, third), (third, first)].into_iter()
+}
+
+fn undirected_edge(first: usize, second: usize) -> (usize, usize) {
+    (first.min(second), first.max(second))
+}
+
+fn opposite_vertex(triangle: [usize; 3], start: usize, end: usize) -> usize {
+    *triangle
+        .iter()
+        .find(|&&index| index != start && index != end)
+        .expect("Triangle is degenerate")
+}
+
+/// Returns the directed edges of the given counter clockwise triangles
+/// that are not shared with another one of the triangles
+fn boundary_edges(triangles: &[[usize; 3]]) -> impl Iterator<Item = (usize, usize)> {
+    let edges: HashSet<_> = triangles
+        .iter()
+        .flat_map(|&triangle| triangle_edges(triangle))
+        .collect();
+    let boundary_edges: Vec<_> = edges
+        .iter()
+        .copied()
+        .filter(|&(start, end)| !edges.contains(&(end, start)))
+        .collect();
+    boundary_edges.into_iter()
+}
+
+/// Returns twice the signed area of the triangle `first`, `second`, `third`,
+/// which is positive if the triangle is counter clockwise
+fn orientation(first: Point, second: Point, third: Point) -> f64 {
+    Vector::from(second - first).cross_product(Vector::from(third - first))
+}
+
+/// Checks whether two segments cross each other at a single point
+/// that is not an endpoint of either segment
+fn crosses(
+    (first_start, first_end): (Point, Point),
+    (second_start, second_end): (Point, Point),
+) -> bool {
+    let opposite_sides = |start: Point, end: Point, first: Point, second: Point| {
+        let first_orientation = orientation(start, end, first);
+        let second_orientation = orientation(start, end, second);
+        (first_orientation > 0.0 && second_orientation < 0.0)
+            || (first_orientation < 0.0 && second_orientation > 0.0)
+    };
+    opposite_sides(first_start, first_end, second_start, second_end)
+        && opposite_sides(second_start, second_end, first_start, first_end)
+}
+
+/// Returns a value that is positive if `point` lies inside the circumcircle
+/// of the counter clockwise `triangle`, zero if it lies on it and negative otherwise
+fn in_circumcircle(triangle: [Point; 3], point: Point) -> f64 {
+    let [first, second, third] = triangle;
+    let first = first - point;
+    let second = second - point;
+    let third = third - point;
+
+    let squared_length = |point: Point| point.x * point.x + point.y * point.y;
+    squared_length(first) * (second.x * third.y - third.x * second.y)
+        - squared_length(second) * (first.x * third.y - third.x * first.y)
+        + squared_length(third) * (first.x * second.y - second.x * first.y)
+}
+
+/// The reason why a [`DelaunayTriangulation`] could not be created
+///
+/// [`DelaunayTriangulation`]: ./struct.DelaunayTriangulation.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DelaunayError {
+    /// Fewer than three points were given
+    TooFewPoints {
+        /// The number of points that were given
+        count: usize,
+    },
+    /// A point had a coordinate that was `NaN` or infinite
+    NonFinitePoint {
+        /// The index of the offending point
+        index: usize,
+    },
+    /// A point was given more than once
+    DuplicatePoint {
+        /// The index of the repetition
+        index: usize,
+    },
+    /// All points lie on a single line, so no triangle can be formed
+    CollinearPoints,
+    /// A constrained edge referred to a point that doesn't exist, connected a point
+    /// to itself or passed through another point
+    InvalidConstraint {
+        /// The index of the offending constrained edge
+        index: usize,
+    },
+    /// Two constrained edges crossed each other
+    CrossingConstraints {
+        /// The index of the first constrained edge
+        first: usize,
+        /// The index of the second constrained edge
+        second: usize,
+    },
+}
+
+impl fmt::Display for DelaunayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DelaunayError::TooFewPoints { count } => write!(
+                f,
+                "A triangulation needs at least 3 points, but {} were given",
+                count
+            ),
+            DelaunayError::NonFinitePoint { index } => {
+                write!(
+                    f,
+                    "Point {} has a coordinate that is NaN or infinite",
+                    index
+                )
+            }
+            DelaunayError::DuplicatePoint { index } => {
+                write!(f, "Point {} repeats an earlier point", index)
+            }
+            DelaunayError::CollinearPoints => write!(f, "All points lie on a single line"),
+            DelaunayError::InvalidConstraint { index } => write!(
+                f,
+                "Constrained edge {} does not connect two distinct points \
+                 or passes through another point",
+                index
+            ),
+            DelaunayError::CrossingConstraints { first, second } => write!(
+                f,
+                "Constrained edges {} and {} cross each other",
+                first, second
+            ),
+        }
+    }
+}
+
+impl Error for DelaunayError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_is_delaunay(triangulation: &DelaunayTriangulation) {
+        let points = triangulation.points();
+        for &[first, second, third] in triangulation.triangles() {
+            let triangle = [points[first], points[second], points[third]];
+            assert!(orientation(triangle[0], triangle[1], triangle[2]) > 0.0);
+            for (index, &point) in points.iter().enumerate() {
+                if ![first, second, third].contains(&index) {
+                    assert!(
+                        in_circumcircle(triangle, point) <= 0.000_001,
+                        "Point {} lies inside the circumcircle of {:?}",
+                        index,
+                        [first, second, third]
+                    );
+                }
+            }
+        }
+    }
+
+    fn scattered_points() -> Vec<Point> {
+        // A deterministic, irregular point cloud
+        (0..40)
+            .map(|index| {
+                let index = f64::from(index);
+                Point {
+                    x: (index * 7.3).sin() * 50.0 + (index * 1.7).cos() * 20.0,
+                    y: (index * 3.1).cos() * 50.0 + (index * 2.3).sin() * 20.0,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn triangulates_square_with_center() {
+        let points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+            Point { x: 5.0, y: 5.0 },
+        ];
+
+        let triangulation = DelaunayTriangulation::try_new(&points).unwrap();
+
+        assert_eq!(4, triangulation.triangles().len());
+        assert_eq!(
+            vec![
+                (0, 1),
+                (0, 3),
+                (0, 4),
+                (1, 2),
+                (1, 4),
+                (2, 3),
+                (2, 4),
+                (3, 4)
+            ],
+            triangulation.edges()
+        );
+        assert_eq!(vec![1, 3, 4], triangulation.adjacency()[0]);
+        assert_is_delaunay(&triangulation);
+    }
+
+    #[test]
+    fn triangulates_scattered_points() {
+        let points = scattered_points();
+
+        let triangulation = DelaunayTriangulation::try_new(&points).unwrap();
+
+        assert_is_delaunay(&triangulation);
+        // Euler's formula: A triangulation of n points with h points on
+        // the convex hull contains 2n - 2 - h triangles
+        let hull_points = crate::ConvexHull::try_new(&points).unwrap().count();
+        assert_eq!(
+            2 * points.len() - 2 - hull_points,
+            triangulation.triangles().len()
+        );
+    }
+
+    #[test]
+    fn triangulates_grid_of_cocircular_points() {
+        let points: Vec<_> = (0..5)
+            .flat_map(|x| {
+                (0..5).map(move |y| Point {
+                    x: f64::from(x),
+                    y: f64::from(y),
+                })
+            })
+            .collect();
+
+        let triangulation = DelaunayTriangulation::try_new(&points).unwrap();
+
+        assert_eq!(32, triangulation.triangles().len());
+        assert_is_delaunay(&triangulation);
+    }
+
+    #[test]
+    fn constrained_edge_is_preserved() {
+        let points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 5.0, y: -1.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 5.0, y: 1.0 },
+        ];
+
+        let unconstrained = DelaunayTriangulation::try_new(&points).unwrap();
+        let constrained = DelaunayTriangulation::try_with_constraints(&points, &[(2, 0)]).unwrap();
+
+        assert!(!unconstrained.edges().contains(&(0, 2)));
+        assert!(constrained.edges().contains(&(0, 2)));
+        assert_eq!(2, constrained.triangles().len());
+    }
+
+    #[test]
+    fn constrained_edges_preserve_concave_boundary() {
+        let mut points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 20.0, y: 0.0 },
+            Point { x: 20.0, y: 20.0 },
+            Point { x: 10.0, y: 2.0 },
+            Point { x: 0.0, y: 20.0 },
+        ];
+        let boundary: Vec<_> = (0..points.len())
+            .map(|index| (index, (index + 1) % points.len()))
+            .collect();
+        points.push(Point { x: 3.0, y: 4.0 });
+        points.push(Point { x: 17.0, y: 4.0 });
+
+        let triangulation =
+            DelaunayTriangulation::try_with_constraints(&points, &boundary).unwrap();
+
+        let edges = triangulation.edges();
+        for &(start, end) in &boundary {
+            assert!(edges.contains(&undirected_edge(start, end)));
+        }
+        for &[first, second, third] in triangulation.triangles() {
+            assert!(orientation(points[first], points[second], points[third]) > 0.0);
+        }
+    }
+
+    #[test]
+    fn try_new_errors_for_too_few_points() {
+        assert_eq!(
+            Err(DelaunayError::TooFewPoints { count: 2 }),
+            DelaunayTriangulation::try_new(&[Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 0.0 }])
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_duplicate_point() {
+        let points = [
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 1.0, y: 0.0 },
+        ];
+        assert_eq!(
+            Err(DelaunayError::DuplicatePoint { index: 3 }),
+            DelaunayTriangulation::try_new(&points)
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_collinear_points() {
+        let points = [
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 2.0, y: 2.0 },
+        ];
+        assert_eq!(
+            Err(DelaunayError::CollinearPoints),
+            DelaunayTriangulation::try_new(&points)
+        );
+    }
+
+    #[test]
+    fn try_with_constraints_errors_for_constraint_through_point() {
+        let points = [
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 2.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+        ];
+        assert_eq!(
+            Err(DelaunayError::InvalidConstraint { index: 1 }),
+            DelaunayTriangulation::try_with_constraints(&points, &[(0, 1), (0, 2)])
+        );
+    }
+
+    #[test]
+    fn try_with_constraints_errors_for_crossing_constraints() {
+        let points = [
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+        ];
+        assert_eq!(
+            Err(DelaunayError::CrossingConstraints {
+                first: 0,
+                second: 1
+            }),
+            DelaunayTriangulation::try_with_constraints(&points, &[(0, 2), (1, 3)])
+        );
+    }
+}
@@ -0,0 +1,354 @@
+//! A convex hull that is updated as points are inserted and removed
+
+use crate::convex_hull::{cross_product, half_hull};
+use crate::{CollinearPoints, Point};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+/// The [convex hull] of a changing set of [`Point`]s.
+///
+/// Inserting a point takes O(log n) time if it lies inside the hull and
+/// O(log n + h) otherwise, where h is the number of hull vertices.
+/// Removing a point that is not a hull vertex takes O(log n) time,
+/// removing a hull vertex rebuilds the hull from the stored points in O(n).
+///
+/// Points may be inserted more than once, in which case they stay part of
+/// the set until they have been removed as often as they were inserted.
+/// Like [`ConvexHull::try_new`], only the corners of the hull are reported.
+///
+/// # Examples
+/// ```
+/// use myelin_geometry::{DynamicConvexHull, Point};
+///
+/// let mut convex_hull = DynamicConvexHull::new();
+/// for &point in &[
+///     Point { x: 0.0, y: 0.0 },
+///     Point { x: 10.0, y: 0.0 },
+///     Point { x: 5.0, y: 10.0 },
+///     Point { x: 5.0, y: 2.0 },
+/// ] {
+///     convex_hull.try_insert(point).expect("Point is NaN or infinite");
+/// }
+/// assert_eq!(3, convex_hull.vertices().count());
+///
+/// convex_hull.remove(Point { x: 5.0, y: 10.0 });
+/// let vertices: Vec<_> = convex_hull.vertices().collect();
+/// assert_eq!(
+///     vec![
+///         Point { x: 0.0, y: 0.0 },
+///         Point { x: 10.0, y: 0.0 },
+///         Point { x: 5.0, y: 2.0 },
+///     ],
+///     vertices
+/// );
+/// ```
+///
+/// [convex hull]: https://en.wikipedia.org/wiki/Convex_hull
+/// [`Point`]: ./struct.Point.html
+/// [`ConvexHull::try_new`]: ./struct.ConvexHull.html#method.try_new
+#[derive(Debug, Clone, Default)]
+pub struct DynamicConvexHull {
+    /// How often each point has been inserted
+    points: BTreeMap<OrderedPoint, usize>,
+    /// The lower hull, ordered from left to right
+    lower_hull: Vec<Point>,
+    /// The upper hull, ordered from left to right
+    upper_hull: Vec<Point>,
+}
+
+/// A [`Point`] that is known to be finite and can thus be totally ordered
+///
+/// [`Point`]: ./struct.Point.html
+#[derive(Debug, PartialEq, Copy, Clone)]
+struct OrderedPoint(Point);
+
+impl Eq for OrderedPoint {}
+
+impl PartialOrd for OrderedPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// The side of the hull a chain of vertices belongs to
+#[derive(Debug, Copy, Clone)]
+enum Chain {
+    Lower,
+    Upper,
+}
+
+impl Chain {
+    /// Checks if the chain turns the right way at `middle`
+    /// for `middle` to be a corner of the hull
+    fn is_corner(self, start: Point, middle: Point, end: Point) -> bool {
+        let turn = cross_product(start, middle, end);
+        match self {
+            Chain::Lower => turn > 0.0,
+            Chain::Upper => turn < 0.0,
+        }
+    }
+}
+
+impl DynamicConvexHull {
+    /// Creates an empty [`DynamicConvexHull`]
+    ///
+    /// [`DynamicConvexHull`]: ./struct.DynamicConvexHull.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `point` to the set and updates the hull.
+    ///
+    /// # Errors
+    /// Returns [`DynamicConvexHullError::NonFinitePoint`] if the point is `NaN` or infinite.
+    ///
+    /// [`DynamicConvexHullError::NonFinitePoint`]: ./enum.DynamicConvexHullError.html#variant.NonFinitePoint
+    pub fn try_insert(&mut self, point: Point) -> Result<(), DynamicConvexHullError> {
+        if !point.x.is_finite() || !point.y.is_finite() {
+            return Err(DynamicConvexHullError::NonFinitePoint);
+        }
+
+        *self.points.entry(OrderedPoint(point)).or_insert(0) += 1;
+        insert_into_chain(&mut self.lower_hull, point, Chain::Lower);
+        insert_into_chain(&mut self.upper_hull, point, Chain::Upper);
+        Ok(())
+    }
+
+    /// Removes one occurrence of `point` from the set and updates the hull.
+    /// Returns wether the point was part of the set.
+    pub fn remove(&mut self, point: Point) -> bool {
+        let key = OrderedPoint(point);
+        match self.points.get_mut(&key) {
+            None => return false,
+            Some(count) if *count > 1 => {
+                *count -= 1;
+                return true;
+            }
+            Some(_) => {
+                self.points.remove(&key);
+            }
+        }
+
+        if self.lower_hull.contains(&point) || self.upper_hull.contains(&point) {
+            self.rebuild();
+        }
+        true
+    }
+
+    /// Returns the number of points in the set, counting repeated insertions
+    pub fn len(&self) -> usize {
+        self.points.values().sum()
+    }
+
+    /// Returns wether the set doesn't contain any points
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Iterates over the vertices of the hull in counter clockwise order,
+    /// starting at the same vertex as [`ConvexHull`]
+    ///
+    /// [`ConvexHull`]: ./struct.ConvexHull.html
+    pub fn vertices(&self) -> impl Iterator<Item = Point> + '_ {
+        let upper_hull_without_ends = self
+            .upper_hull
+            .iter()
+            .skip(1)
+            .take(self.upper_hull.len().saturating_sub(2))
+            .rev();
+        self.lower_hull
+            .iter()
+            .chain(upper_hull_without_ends)
+            .copied()
+    }
+
+    fn rebuild(&mut self) {
+        let sorted_points: Vec<_> = self
+            .points
+            .keys()
+            .map(|&OrderedPoint(point)| point)
+            .collect();
+        self.lower_hull = half_hull(sorted_points.iter(), CollinearPoints::Discard);
+        self.upper_hull = half_hull(sorted_points.iter().rev(), CollinearPoints::Discard);
+        self.upper_hull.reverse();
+    }
+}
+
+/// Inserts `point` into a chain of hull vertices ordered from left to right,
+/// removing vertices that stop being corners
+fn insert_into_chain(vertices: &mut Vec<Point>, point: Point, chain: Chain) {
+    let position = vertices.partition_point(|&vertex| OrderedPoint(vertex) < OrderedPoint(point));
+    if vertices.get(position) == Some(&point) {
+        return;
+    }
+    let is_inside_chain = position > 0
+        && position < vertices.len()
+        && !chain.is_corner(vertices[position - 1], point, vertices[position]);
+    if is_inside_chain {
+        return;
+    }
+
+    vertices.insert(position, point);
+    let mut position = position;
+    while position >= 2
+        && !chain.is_corner(
+            vertices[position - 2],
+            vertices[position - 1],
+            vertices[position],
+        )
+    {
+        vertices.remove(position - 1);
+        position -= 1;
+    }
+    while position + 2 < vertices.len()
+        && !chain.is_corner(
+            vertices[position],
+            vertices[position + 1],
+            vertices[position + 2],
+        )
+    {
+        vertices.remove(position + 1);
+    }
+}
+
+/// The reason why a point could not be inserted into a [`DynamicConvexHull`]
+///
+/// [`DynamicConvexHull`]: ./struct.DynamicConvexHull.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DynamicConvexHullError {
+    /// The point had a coordinate that was `NaN` or infinite
+    NonFinitePoint,
+}
+
+impl fmt::Display for DynamicConvexHullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicConvexHullError::NonFinitePoint => {
+                write!(f, "Point has a coordinate that is NaN or infinite")
+            }
+        }
+    }
+}
+
+impl Error for DynamicConvexHullError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConvexHull;
+
+    fn scattered_points() -> Vec<Point> {
+        (0..60)
+            .map(|index| {
+                let index = f64::from(index);
+                Point {
+                    x: ((index * 7.3).sin() * 20.0).round(),
+                    y: ((index * 3.1).cos() * 20.0).round(),
+                }
+            })
+            .collect()
+    }
+
+    fn assert_matches_convex_hull(dynamic_convex_hull: &DynamicConvexHull, points: &[Point]) {
+        let expected: Vec<_> = ConvexHull::try_new(points).unwrap().collect();
+        let actual: Vec<_> = dynamic_convex_hull.vertices().collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn empty_hull_has_no_vertices() {
+        let convex_hull = DynamicConvexHull::new();
+
+        assert!(convex_hull.is_empty());
+        assert_eq!(0, convex_hull.vertices().count());
+    }
+
+    #[test]
+    fn insertion_matches_convex_hull() {
+        let points = scattered_points();
+        let mut convex_hull = DynamicConvexHull::new();
+
+        for (count, &point) in points.iter().enumerate() {
+            convex_hull.try_insert(point).unwrap();
+            assert_matches_convex_hull(&convex_hull, &points[..=count]);
+        }
+    }
+
+    #[test]
+    fn removal_matches_convex_hull() {
+        let mut points = scattered_points();
+        let mut convex_hull = DynamicConvexHull::new();
+        for &point in &points {
+            convex_hull.try_insert(point).unwrap();
+        }
+
+        while points.len() > 1 {
+            let point = points.remove(points.len() / 2);
+            assert!(convex_hull.remove(point));
+            assert_matches_convex_hull(&convex_hull, &points);
+        }
+    }
+
+    #[test]
+    fn collinear_points_are_not_reported() {
+        let mut convex_hull = DynamicConvexHull::new();
+        for &(x, y) in &[(0.0, 0.0), (10.0, 0.0), (5.0, 0.0), (0.0, 10.0), (0.0, 5.0)] {
+            convex_hull.try_insert(Point { x, y }).unwrap();
+        }
+
+        let vertices: Vec<_> = convex_hull.vertices().collect();
+
+        assert_eq!(
+            vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 10.0, y: 0.0 },
+                Point { x: 0.0, y: 10.0 },
+            ],
+            vertices
+        );
+    }
+
+    #[test]
+    fn repeated_point_stays_until_removed_as_often_as_inserted() {
+        let corner = Point { x: 10.0, y: 10.0 };
+        let mut convex_hull = DynamicConvexHull::new();
+        for &point in &[
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            corner,
+            corner,
+        ] {
+            convex_hull.try_insert(point).unwrap();
+        }
+
+        assert!(convex_hull.remove(corner));
+        assert_eq!(3, convex_hull.len());
+        assert!(convex_hull.vertices().any(|vertex| vertex == corner));
+
+        assert!(convex_hull.remove(corner));
+        assert!(!convex_hull.vertices().any(|vertex| vertex == corner));
+        assert!(!convex_hull.remove(corner));
+    }
+
+    #[test]
+    fn try_insert_errors_for_nan() {
+        let mut convex_hull = DynamicConvexHull::new();
+
+        assert_eq!(
+            Err(DynamicConvexHullError::NonFinitePoint),
+            convex_hull.try_insert(Point {
+                x: f64::NAN,
+                y: 0.0
+            })
+        );
+        assert!(convex_hull.is_empty());
+    }
+}
@@ -0,0 +1,256 @@
+//! Rigid placements of shapes in the world
+
+use crate::{Point, Radians, Transform, Transformable, Vector};
+use serde::{Deserialize, Serialize};
+
+/// The pose of a rigid body, i.e. its position and orientation.
+///
+/// An isometry maps from a body's local frame, in which its shape is stored
+/// once, to the world frame. Rotations happen around the local origin,
+/// in the direction described in [`Radians`], followed by a
+/// translation to the position.
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │         ╱╲
+/// │        ╱  ╲ ← Shape, rotated by the orientation
+/// │        ╲ ● ╲
+/// │         ╲  ╱  ● = Position
+/// │          ╲╱
+/// y
+/// ```
+///
+/// # Examples
+/// ```
+/// use myelin_geometry::{Isometry, PolygonBuilder, Radians, Transformable};
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// let local_shape = PolygonBuilder::default()
+///     .vertex(-1.0, -1.0)
+///     .vertex(1.0, -1.0)
+///     .vertex(1.0, 1.0)
+///     .vertex(-1.0, 1.0)
+///     .build()
+///     .unwrap();
+/// let pose = Isometry::new((10.0, 5.0).into(), Radians::try_new(FRAC_PI_2).unwrap());
+///
+/// let world_shape = local_shape.transform(pose.into());
+/// assert!(world_shape.contains_point((10.5, 5.5).into()));
+/// ```
+///
+/// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+#[derive(Debug, PartialEq, Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Isometry {
+    /// The position of the local origin in the world
+    pub position: Point,
+    /// The rotation of the local frame relative to the world
+    pub orientation: Radians,
+}
+
+impl Isometry {
+    /// Creates a new [`Isometry`] placing the local origin at `position`
+    ///
+    /// [`Isometry`]: ./struct.Isometry.html
+    pub fn new(position: Point, orientation: Radians) -> Self {
+        Self {
+            position,
+            orientation,
+        }
+    }
+
+    /// Places a `local` pose, which is given relative to this pose, in the world.
+    /// This is used to attach bodies to each other, e.g. a sensor to an organism.
+    pub fn compose(self, local: Isometry) -> Isometry {
+        Isometry {
+            position: self.transform_point(local.position),
+            orientation: self.orientation + local.orientation,
+        }
+    }
+
+    /// Returns the isometry that maps from the world frame to the local frame
+    pub fn inverse(self) -> Isometry {
+        let orientation = -self.orientation;
+        let inverse_rotation = Transform::rotation(orientation);
+        Isometry {
+            position: Point::from(
+                Vector::from(self.position)
+                    .transform(inverse_rotation)
+                    .negative(),
+            ),
+            orientation,
+        }
+    }
+
+    /// Maps a point from the local frame to the world frame
+    pub fn transform_point(self, point: Point) -> Point {
+        point.transform(self.into())
+    }
+
+    /// Maps a point from the world frame to the local frame
+    pub fn inverse_transform_point(self, point: Point) -> Point {
+        self.inverse().transform_point(point)
+    }
+
+    /// Maps a vector from the local frame to the world frame.
+    /// Vectors are only rotated, not translated.
+    pub fn transform_vector(self, vector: Vector) -> Vector {
+        vector.transform(Transform::rotation(self.orientation))
+    }
+
+    /// Maps a vector from the world frame to the local frame.
+    /// Vectors are only rotated, not translated.
+    pub fn inverse_transform_vector(self, vector: Vector) -> Vector {
+        self.inverse().transform_vector(vector)
+    }
+
+    /// Interpolates between this pose and `other`. The position moves along
+    /// a straight line, while the orientation turns along the shorter way around.
+    /// A `factor` of 0 returns this pose, a `factor` of 1 returns `other`.
+    ///
+    /// # Panics
+    /// Panics if `factor` is `NaN` or infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Isometry, Point, Radians};
+    /// use nearly_eq::assert_nearly_eq;
+    ///
+    /// let start = Isometry::new(Point { x: 0.0, y: 0.0 }, Radians::try_from_degrees(350.0).unwrap());
+    /// let end = Isometry::new(Point { x: 10.0, y: 0.0 }, Radians::try_from_degrees(30.0).unwrap());
+    ///
+    /// let halfway = start.interpolate(end, 0.5);
+    /// assert_eq!(Point { x: 5.0, y: 0.0 }, halfway.position);
+    /// assert_nearly_eq!(Radians::try_from_degrees(10.0).unwrap().value(), halfway.orientation.value());
+    /// ```
+    pub fn interpolate(self, other: Isometry, factor: f64) -> Isometry {
+        let movement = Vector::from(other.position - self.position) * factor;
+        Isometry {
+            position: Point::from(Vector::from(self.position) + movement),
+            orientation: self.orientation.interpolate(other.orientation, factor),
+        }
+    }
+}
+
+impl From<Isometry> for Transform {
+    fn from(isometry: Isometry) -> Self {
+        Transform::rotation(isometry.orientation)
+            .then(Transform::translation(Vector::from(isometry.position)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PolygonBuilder;
+    use nearly_eq::assert_nearly_eq;
+    use std::f64::consts::{FRAC_PI_2, TAU};
+
+    fn assert_points_nearly_eq(expected: Point, actual: Point) {
+        assert_nearly_eq!(expected.x, actual.x);
+        assert_nearly_eq!(expected.y, actual.y);
+    }
+
+    fn pose() -> Isometry {
+        Isometry::new(Point { x: 3.0, y: -2.0 }, Radians::try_new(1.0).unwrap())
+    }
+
+    #[test]
+    fn transform_matches_rotating_then_translating_polygon() {
+        let polygon = PolygonBuilder::default()
+            .vertex(0.0, 0.0)
+            .vertex(2.0, 0.0)
+            .vertex(0.0, 1.0)
+            .build()
+            .unwrap();
+
+        let placed = polygon.transform(pose().into());
+        let expected = polygon
+            .rotate_around_point(pose().orientation, Point::default())
+            .translate(pose().position);
+
+        for (&expected, &actual) in expected.vertices().iter().zip(placed.vertices()) {
+            assert_points_nearly_eq(expected, actual);
+        }
+    }
+
+    #[test]
+    fn inverse_transform_point_undoes_transform_point() {
+        let point = Point { x: 7.0, y: 1.5 };
+
+        assert_points_nearly_eq(
+            point,
+            pose().inverse_transform_point(pose().transform_point(point)),
+        );
+        assert_points_nearly_eq(
+            point,
+            pose().transform_point(pose().inverse_transform_point(point)),
+        );
+    }
+
+    #[test]
+    fn vectors_are_only_rotated() {
+        let pose = Isometry::new(
+            Point { x: 10.0, y: 10.0 },
+            Radians::try_new(FRAC_PI_2).unwrap(),
+        );
+
+        let vector = pose.transform_vector(Vector { x: 1.0, y: 0.0 });
+
+        assert_nearly_eq!(0.0, vector.x);
+        assert_nearly_eq!(-1.0, vector.y);
+        let original = pose.inverse_transform_vector(vector);
+        assert_nearly_eq!(1.0, original.x);
+        assert_nearly_eq!(0.0, original.y);
+    }
+
+    #[test]
+    fn compose_applies_local_pose_first() {
+        let parent = pose();
+        let child = Isometry::new(Point { x: 1.0, y: 0.5 }, Radians::try_new(6.0).unwrap());
+        let point = Point { x: -2.0, y: 4.0 };
+
+        let composed = parent.compose(child);
+
+        assert_points_nearly_eq(
+            parent.transform_point(child.transform_point(point)),
+            composed.transform_point(point),
+        );
+        assert!(composed.orientation.value() < TAU);
+    }
+
+    #[test]
+    fn composing_with_inverse_yields_identity() {
+        let identity = pose().compose(pose().inverse());
+
+        assert_points_nearly_eq(Point::default(), identity.position);
+        let orientation = identity.orientation.value();
+        assert!(!(0.000_001..=TAU - 0.000_001).contains(&orientation));
+    }
+
+    #[test]
+    fn interpolate_returns_end_poses() {
+        let other = Isometry::new(Point { x: -4.0, y: 8.0 }, Radians::try_new(5.0).unwrap());
+
+        assert_eq!(pose(), pose().interpolate(other, 0.0));
+        let end = pose().interpolate(other, 1.0);
+        assert_points_nearly_eq(other.position, end.position);
+        assert_nearly_eq!(other.orientation.value(), end.orientation.value());
+    }
+
+    #[test]
+    fn interpolate_turns_the_shorter_way() {
+        let start = Isometry::new(Point::default(), Radians::try_new(0.5).unwrap());
+        let end = Isometry::new(Point::default(), Radians::try_new(TAU - 0.5).unwrap());
+
+        let quarter = start.interpolate(end, 0.25);
+
+        assert_nearly_eq!(0.25, quarter.orientation.value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn interpolate_panics_with_non_finite_factor() {
+        pose().interpolate(Isometry::default(), f64::INFINITY);
+    }
+}
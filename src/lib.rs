@@ -7,7 +7,6 @@
     clippy::doc_markdown,
     clippy::unimplemented
 )]
-#![allow(clippy::result_unit_err)]
 
 mod aabb;
 pub use self::aabb::*;
@@ -15,17 +14,64 @@ pub use self::aabb::*;
 mod radians;
 pub use self::radians::*;
 
+mod rotation;
+pub use self::rotation::*;
+
 mod polygon;
 pub use self::polygon::*;
 
+mod simple_polygon;
+pub use self::simple_polygon::*;
+
+mod polygon_with_holes;
+pub use self::polygon_with_holes::*;
+
+mod multi_polygon;
+pub use self::multi_polygon::*;
+
+mod triangulation;
+pub use self::triangulation::*;
+
+mod convex_decomposition;
+
+mod delaunay;
+pub use self::delaunay::*;
+
+mod voronoi;
+pub use self::voronoi::*;
+
 mod vector;
 pub use self::vector::*;
 
 mod point;
 pub use self::point::*;
 
+mod transform;
+pub use self::transform::*;
+
+mod isometry;
+pub use self::isometry::*;
+
 mod convex_hull;
 pub use self::convex_hull::*;
 
+mod dynamic_convex_hull;
+pub use self::dynamic_convex_hull::*;
+
 mod intersects;
 pub use self::intersects::*;
+
+mod circle;
+pub use self::circle::*;
+
+mod segment;
+pub use self::segment::*;
+
+mod ray;
+pub use self::ray::*;
+
+mod proximity;
+pub use self::proximity::*;
+
+mod manifold;
+pub use self::manifold::*;
@@ -0,0 +1,72 @@
+use crate::{Point, Vector};
+
+/// Describes how two overlapping shapes penetrate each other
+/// and how they can be pushed apart.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Manifold {
+    /// The unit vector along which the shapes overlap the least,
+    /// pointing from the first shape towards the second shape
+    pub normal: Vector,
+    /// How far the shapes penetrate each other along the `normal`
+    pub depth: f64,
+}
+
+impl Manifold {
+    /// Returns the minimum translation vector, i.e. the shortest translation that,
+    /// when applied to the second shape, moves it out of the first shape.
+    pub fn minimum_translation_vector(&self) -> Vector {
+        self.normal * self.depth
+    }
+}
+
+/// A single point at which two overlapping shapes touch
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Contact {
+    /// The location of the contact, lying on the boundary of the incident shape
+    pub point: Point,
+    /// How far the shapes penetrate each other at this point
+    pub depth: f64,
+    /// Identifies the features that produced this contact
+    pub id: ContactId,
+}
+
+/// Identifies which features of two shapes produced a [`Contact`].
+///
+/// The id stays the same across frames as long as the same features
+/// are touching, which allows solvers to warm-start.
+///
+/// [`Contact`]: ./struct.Contact.html
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct ContactId {
+    /// The feature of the first shape
+    pub first: Feature,
+    /// The feature of the second shape
+    pub second: Feature,
+}
+
+/// A vertex or an edge of a polygon
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Feature {
+    /// The vertex at the given index
+    Vertex(usize),
+    /// The edge starting at the vertex at the given index and ending
+    /// at the next vertex in counter clockwise order
+    Edge(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimum_translation_vector_is_scaled_normal() {
+        let manifold = Manifold {
+            normal: Vector { x: 0.0, y: -1.0 },
+            depth: 2.5,
+        };
+        assert_eq!(
+            Vector { x: 0.0, y: -2.5 },
+            manifold.minimum_translation_vector()
+        );
+    }
+}
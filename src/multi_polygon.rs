@@ -0,0 +1,262 @@
+//! Collections of polygons that are treated as a single region
+
+use crate::{Aabb, Intersects, Point, Polygon, PolygonWithHoles, SimplePolygon};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+mod boolean_operations;
+
+/// A region consisting of any number of [`PolygonWithHoles`] that don't overlap.
+/// This is the result of the boolean operations
+/// [`union`], [`intersection`], [`difference`] and [`symmetric_difference`].
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │   ┌─────────────┐
+/// │   │   ┌───┐     │       ┌─────┐
+/// │   │   └───┘     │       │     │
+/// │   └─────────────┘       └─────┘
+/// │        ↑ Polygon           ↑ Polygon
+/// y
+/// ```
+///
+/// Deserialization goes through [`MultiPolygon::try_new`],
+/// so overlapping polygons are rejected.
+///
+/// [`PolygonWithHoles`]: ./struct.PolygonWithHoles.html
+/// [`union`]: ./struct.MultiPolygon.html#method.union
+/// [`intersection`]: ./struct.MultiPolygon.html#method.intersection
+/// [`difference`]: ./struct.MultiPolygon.html#method.difference
+/// [`symmetric_difference`]: ./struct.MultiPolygon.html#method.symmetric_difference
+/// [`MultiPolygon::try_new`]: ./struct.MultiPolygon.html#method.try_new
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedMultiPolygon")]
+pub struct MultiPolygon {
+    polygons: Vec<PolygonWithHoles>,
+}
+
+/// Mirrors the serialized representation of a [`MultiPolygon`]
+/// before its polygons have been validated
+#[derive(Deserialize)]
+#[serde(rename = "MultiPolygon")]
+struct UncheckedMultiPolygon {
+    polygons: Vec<PolygonWithHoles>,
+}
+
+impl TryFrom<UncheckedMultiPolygon> for MultiPolygon {
+    type Error = MultiPolygonError;
+
+    fn try_from(unchecked: UncheckedMultiPolygon) -> Result<Self, Self::Error> {
+        MultiPolygon::try_new(unchecked.polygons)
+    }
+}
+
+impl MultiPolygon {
+    /// Creates a new [`MultiPolygon`] from polygons that may touch each other,
+    /// but don't overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{MultiPolygon, Point, PolygonWithHoles, SimplePolygon};
+    ///
+    /// let square = |x: f64, y: f64| {
+    ///     let exterior = SimplePolygon::try_new(vec![
+    ///         Point { x, y },
+    ///         Point { x: x + 10.0, y },
+    ///         Point {
+    ///             x: x + 10.0,
+    ///             y: y + 10.0,
+    ///         },
+    ///         Point { x, y: y + 10.0 },
+    ///     ])
+    ///     .unwrap();
+    ///     PolygonWithHoles::from(exterior)
+    /// };
+    ///
+    /// let islands = MultiPolygon::try_new(vec![square(0.0, 0.0), square(20.0, 0.0)])
+    ///     .expect("Polygons overlap");
+    /// assert_eq!(200.0, islands.area());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`MultiPolygonError::OverlappingPolygons`] if two polygons overlap.
+    ///
+    /// [`MultiPolygon`]: ./struct.MultiPolygon.html
+    /// [`MultiPolygonError::OverlappingPolygons`]: ./enum.MultiPolygonError.html#variant.OverlappingPolygons
+    pub fn try_new(polygons: Vec<PolygonWithHoles>) -> Result<Self, MultiPolygonError> {
+        let overlapping_polygons = (0..polygons.len())
+            .flat_map(|first| (first + 1..polygons.len()).map(move |second| (first, second)))
+            .find(|&(first, second)| overlap(&polygons[first], &polygons[second]));
+        if let Some((first, second)) = overlapping_polygons {
+            return Err(MultiPolygonError::OverlappingPolygons { first, second });
+        }
+
+        Ok(Self { polygons })
+    }
+
+    /// Returns the polygons making up the region
+    pub fn polygons(&self) -> &[PolygonWithHoles] {
+        &self.polygons
+    }
+
+    /// Returns wether the region doesn't contain any polygons
+    pub fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+
+    /// Returns the area covered by the region
+    pub fn area(&self) -> f64 {
+        self.polygons.iter().map(PolygonWithHoles::area).sum()
+    }
+
+    /// Checks if a given point rests inside any of the polygons or on its boundary
+    pub fn contains_point(&self, point: Point) -> bool {
+        self.polygons
+            .iter()
+            .any(|polygon| polygon.contains_point(point))
+    }
+
+    /// Returns an [`Aabb`] which fully contains the region,
+    /// or `None` if the region is empty.
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    pub fn aabb(&self) -> Option<Aabb> {
+        self.polygons
+            .iter()
+            .map(PolygonWithHoles::aabb)
+            .fold(None, |accumulated, aabb| match accumulated {
+                None => Some(aabb),
+                Some(accumulated) => Some(Aabb {
+                    upper_left: Point {
+                        x: accumulated.upper_left.x.min(aabb.upper_left.x),
+                        y: accumulated.upper_left.y.min(aabb.upper_left.y),
+                    },
+                    lower_right: Point {
+                        x: accumulated.lower_right.x.max(aabb.lower_right.x),
+                        y: accumulated.lower_right.y.max(aabb.lower_right.y),
+                    },
+                }),
+            })
+    }
+}
+
+impl From<PolygonWithHoles> for MultiPolygon {
+    fn from(polygon: PolygonWithHoles) -> Self {
+        Self {
+            polygons: vec![polygon],
+        }
+    }
+}
+
+impl From<SimplePolygon> for MultiPolygon {
+    fn from(polygon: SimplePolygon) -> Self {
+        Self::from(PolygonWithHoles::from(polygon))
+    }
+}
+
+impl From<Polygon> for MultiPolygon {
+    fn from(polygon: Polygon) -> Self {
+        Self::from(SimplePolygon::from(polygon))
+    }
+}
+
+fn overlap(first: &PolygonWithHoles, second: &PolygonWithHoles) -> bool {
+    first.aabb().intersects(&second.aabb())
+        && !MultiPolygon::from(first.clone())
+            .intersection(&MultiPolygon::from(second.clone()))
+            .is_empty()
+}
+
+/// The reason why a [`MultiPolygon`] instance could not be created
+///
+/// [`MultiPolygon`]: ./struct.MultiPolygon.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum MultiPolygonError {
+    /// Two polygons overlapped each other
+    OverlappingPolygons {
+        /// The index of the first overlapping polygon
+        first: usize,
+        /// The index of the second overlapping polygon
+        second: usize,
+    },
+}
+
+impl fmt::Display for MultiPolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultiPolygonError::OverlappingPolygons { first, second } => {
+                write!(f, "Polygons at indices {} and {} overlap", first, second)
+            }
+        }
+    }
+}
+
+impl Error for MultiPolygonError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nearly_eq::assert_nearly_eq;
+
+    fn square(x: f64, y: f64, size: f64) -> PolygonWithHoles {
+        PolygonWithHoles::from(
+            SimplePolygon::try_new(vec![
+                Point { x, y },
+                Point { x: x + size, y },
+                Point {
+                    x: x + size,
+                    y: y + size,
+                },
+                Point { x, y: y + size },
+            ])
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn try_new_accepts_touching_polygons() {
+        let multi_polygon =
+            MultiPolygon::try_new(vec![square(0.0, 0.0, 10.0), square(10.0, 0.0, 10.0)]).unwrap();
+
+        assert_nearly_eq!(200.0, multi_polygon.area());
+    }
+
+    #[test]
+    fn try_new_errors_for_overlapping_polygons() {
+        assert_eq!(
+            Err(MultiPolygonError::OverlappingPolygons {
+                first: 0,
+                second: 2
+            }),
+            MultiPolygon::try_new(vec![
+                square(0.0, 0.0, 10.0),
+                square(20.0, 0.0, 10.0),
+                square(5.0, 5.0, 10.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn contains_point_in_any_polygon() {
+        let multi_polygon =
+            MultiPolygon::try_new(vec![square(0.0, 0.0, 10.0), square(20.0, 0.0, 10.0)]).unwrap();
+
+        assert!(multi_polygon.contains_point(Point { x: 25.0, y: 5.0 }));
+        assert!(!multi_polygon.contains_point(Point { x: 15.0, y: 5.0 }));
+    }
+
+    #[test]
+    fn aabb_encloses_all_polygons() {
+        let multi_polygon =
+            MultiPolygon::try_new(vec![square(0.0, 0.0, 10.0), square(20.0, 5.0, 10.0)]).unwrap();
+
+        assert_eq!(
+            Some(Aabb::try_new((0.0, 0.0), (30.0, 15.0)).unwrap()),
+            multi_polygon.aabb()
+        );
+        assert_eq!(None, MultiPolygon::default().aabb());
+    }
+}
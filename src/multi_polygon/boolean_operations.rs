@@ -0,0 +1,755 @@
+//! Boolean operations on [`MultiPolygon`]s
+//!
+//! Both operands are broken down into directed edges that have the enclosed area
+//! on their left side, i.e. counter clockwise exteriors and clockwise holes.
+//! All edges are split wherever they intersect or overlap another edge, so that
+//! any two remaining edges either coincide or meet at most at their endpoints.
+//! Every edge is then classified as lying inside or outside of the other operand,
+//! or as being shared with it, which decides if it bounds the result.
+//! Finally, the kept edges are linked into rings.
+
+use super::MultiPolygon;
+use crate::polygon::remove_redundant_vertices;
+use crate::simple_polygon::{ring_contains_point, signed_area};
+use crate::{Point, PolygonWithHoles, Segment, SegmentIntersection, SimplePolygon, Vector};
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+
+/// Relative tolerance under which points are considered to coincide
+const SNAPPING_TOLERANCE: f64 = 0.000_000_001;
+
+impl MultiPolygon {
+    /// Calculates the region covered by this region, `other`, or both.
+    ///
+    /// Polygons that share an edge are merged,
+    /// polygons that only touch at a vertex stay separate.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{MultiPolygon, PolygonBuilder};
+    ///
+    /// let square = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(10.0, 0.0)
+    ///     .vertex(10.0, 10.0)
+    ///     .vertex(0.0, 10.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let first = MultiPolygon::from(square.clone());
+    /// let second = MultiPolygon::from(square.translate((5.0, 5.0).into()));
+    ///
+    /// let union = first.union(&second);
+    /// assert_eq!(1, union.polygons().len());
+    /// assert_eq!(175.0, union.area());
+    /// ```
+    pub fn union(&self, other: &MultiPolygon) -> MultiPolygon {
+        apply(Operation::Union, self, other)
+    }
+
+    /// Calculates the region covered by both this region and `other`.
+    /// Parts in which the regions only touch are not included.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{MultiPolygon, PolygonBuilder};
+    ///
+    /// let square = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(10.0, 0.0)
+    ///     .vertex(10.0, 10.0)
+    ///     .vertex(0.0, 10.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let first = MultiPolygon::from(square.clone());
+    /// let second = MultiPolygon::from(square.translate((5.0, 5.0).into()));
+    ///
+    /// assert_eq!(25.0, first.intersection(&second).area());
+    /// ```
+    pub fn intersection(&self, other: &MultiPolygon) -> MultiPolygon {
+        apply(Operation::Intersection, self, other)
+    }
+
+    /// Calculates the region covered by this region, but not by `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{MultiPolygon, PolygonBuilder};
+    ///
+    /// let square = |x: f64, y: f64, size: f64| {
+    ///     PolygonBuilder::default()
+    ///         .vertex(x, y)
+    ///         .vertex(x + size, y)
+    ///         .vertex(x + size, y + size)
+    ///         .vertex(x, y + size)
+    ///         .build()
+    ///         .unwrap()
+    /// };
+    /// let field = MultiPolygon::from(square(0.0, 0.0, 10.0));
+    /// let pond = MultiPolygon::from(square(4.0, 4.0, 2.0));
+    ///
+    /// let difference = field.difference(&pond);
+    /// assert_eq!(1, difference.polygons()[0].holes().len());
+    /// assert_eq!(96.0, difference.area());
+    /// ```
+    pub fn difference(&self, other: &MultiPolygon) -> MultiPolygon {
+        apply(Operation::Difference, self, other)
+    }
+
+    /// Calculates the region covered by either this region or `other`, but not by both.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{MultiPolygon, PolygonBuilder};
+    ///
+    /// let square = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(10.0, 0.0)
+    ///     .vertex(10.0, 10.0)
+    ///     .vertex(0.0, 10.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let first = MultiPolygon::from(square.clone());
+    /// let second = MultiPolygon::from(square.translate((5.0, 5.0).into()));
+    ///
+    /// let symmetric_difference = first.symmetric_difference(&second);
+    /// assert_eq!(2, symmetric_difference.polygons().len());
+    /// assert_eq!(150.0, symmetric_difference.area());
+    /// ```
+    pub fn symmetric_difference(&self, other: &MultiPolygon) -> MultiPolygon {
+        apply(Operation::SymmetricDifference, self, other)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Operation {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Operand {
+    First,
+    Second,
+}
+
+/// Where an edge of one operand lies relative to the other operand
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum EdgeLocation {
+    Inside,
+    Outside,
+    /// The other operand has the same edge, with its area on the same side
+    SharedWithSameDirection,
+    /// The other operand has the same edge, with its area on the opposite side
+    SharedWithOppositeDirection,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Backward,
+}
+
+impl Operation {
+    /// Decides if an edge of `operand` at `location` bounds the result,
+    /// and in which direction it has to be traversed to keep the result on its left.
+    /// Shared edges are only taken from the first operand so that they are not duplicated.
+    fn kept_direction(self, operand: Operand, location: EdgeLocation) -> Option<Direction> {
+        use self::{Direction::*, EdgeLocation::*, Operand::*, Operation::*};
+
+        match (self, operand, location) {
+            (Union, _, Outside) | (Intersection, _, Inside) => Some(Forward),
+            (Union, First, SharedWithSameDirection)
+            | (Intersection, First, SharedWithSameDirection) => Some(Forward),
+            (Difference, First, Outside) | (Difference, First, SharedWithOppositeDirection) => {
+                Some(Forward)
+            }
+            (Difference, Second, Inside) => Some(Backward),
+            (SymmetricDifference, _, Outside) => Some(Forward),
+            (SymmetricDifference, _, Inside) => Some(Backward),
+            _ => None,
+        }
+    }
+}
+
+/// A directed edge between two vertices, given by their indices
+type Edge = (usize, usize);
+
+/// The vertices of both operands, with coinciding points merged
+#[derive(Debug)]
+struct Vertices {
+    points: Vec<Point>,
+    tolerance: f64,
+}
+
+impl Vertices {
+    /// Returns the index of the vertex at `point`, adding it if necessary
+    fn index_of(&mut self, point: Point) -> usize {
+        let tolerance = self.tolerance;
+        match self
+            .points
+            .iter()
+            .position(|&vertex| Vector::from(point - vertex).magnitude() <= tolerance)
+        {
+            Some(index) => index,
+            None => {
+                self.points.push(point);
+                self.points.len() - 1
+            }
+        }
+    }
+
+    fn segment(&self, (start, end): Edge) -> Segment {
+        Segment {
+            start: self.points[start],
+            end: self.points[end],
+        }
+    }
+}
+
+fn apply(operation: Operation, first: &MultiPolygon, second: &MultiPolygon) -> MultiPolygon {
+    let first_segments = oriented_segments(first);
+    let second_segments = oriented_segments(second);
+    let all_segments: Vec<_> = first_segments
+        .iter()
+        .chain(second_segments.iter())
+        .copied()
+        .collect();
+    if all_segments.is_empty() {
+        return MultiPolygon::default();
+    }
+
+    let scale = scale(&all_segments);
+    let mut vertices = Vertices {
+        points: Vec::new(),
+        tolerance: SNAPPING_TOLERANCE * scale,
+    };
+    let first_edges = split_segments(&all_segments, 0..first_segments.len(), &mut vertices);
+    let second_edges = split_segments(
+        &all_segments,
+        first_segments.len()..all_segments.len(),
+        &mut vertices,
+    );
+
+    let mut result_edges = Vec::new();
+    for &(operand, edges, other_edges) in &[
+        (Operand::First, &first_edges, &second_edges),
+        (Operand::Second, &second_edges, &first_edges),
+    ] {
+        for &edge in edges {
+            let location = locate_edge(edge, other_edges, &vertices);
+            match operation.kept_direction(operand, location) {
+                Some(Direction::Forward) => result_edges.push(edge),
+                Some(Direction::Backward) => result_edges.push((edge.1, edge.0)),
+                None => {}
+            }
+        }
+    }
+    let result_edges = cancel_opposite_edges(result_edges);
+
+    let rings = link_rings(&result_edges, &vertices)
+        .into_iter()
+        .filter_map(|mut ring| {
+            remove_redundant_vertices(&mut ring, vertices.tolerance);
+            let is_degenerate =
+                ring.len() < 3 || signed_area(&ring).abs() <= vertices.tolerance * scale;
+            if is_degenerate {
+                None
+            } else {
+                Some(ring)
+            }
+        });
+    assemble_polygons(rings)
+}
+
+/// Returns the edges of all rings, directed so that the enclosed area lies on their left
+fn oriented_segments(multi_polygon: &MultiPolygon) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    for polygon in multi_polygon.polygons() {
+        let exterior = polygon.exterior();
+        let is_counter_clockwise = signed_area(exterior.vertices()) > 0.0;
+        segments.extend(exterior.segments().map(|segment| {
+            if is_counter_clockwise {
+                segment
+            } else {
+                reversed(segment)
+            }
+        }));
+
+        for hole in polygon.holes() {
+            let is_clockwise = signed_area(hole.vertices()) < 0.0;
+            segments.extend(hole.segments().map(|segment| {
+                if is_clockwise {
+                    segment
+                } else {
+                    reversed(segment)
+                }
+            }));
+        }
+    }
+    segments
+}
+
+fn reversed(segment: Segment) -> Segment {
+    Segment {
+        start: segment.end,
+        end: segment.start,
+    }
+}
+
+/// Returns the largest extent of the segments' bounding box, but at least 1
+fn scale(segments: &[Segment]) -> f64 {
+    let points = || {
+        segments
+            .iter()
+            .flat_map(|segment| vec![segment.start, segment.end])
+    };
+    let extent = |coordinate: fn(Point) -> f64| {
+        let minimum = points().map(coordinate).fold(f64::INFINITY, f64::min);
+        let maximum = points().map(coordinate).fold(f64::NEG_INFINITY, f64::max);
+        maximum - minimum
+    };
+    extent(|point| point.x)
+        .max(extent(|point| point.y))
+        .max(1.0)
+}
+
+/// Splits the segments at `indices` wherever they meet any other segment
+/// and returns the resulting edges of one operand. Edges that appear in both
+/// directions cancel each other out, as they separate two parts of the same operand.
+fn split_segments(
+    segments: &[Segment],
+    indices: std::ops::Range<usize>,
+    vertices: &mut Vertices,
+) -> Vec<Edge> {
+    let mut edges = Vec::new();
+    for index in indices {
+        let segment = segments[index];
+        let mut split_points = vec![segment.start, segment.end];
+        for (other_index, other_segment) in segments.iter().enumerate() {
+            if other_index == index {
+                continue;
+            }
+            match segment.intersection(other_segment) {
+                Some(SegmentIntersection::Point(point)) => split_points.push(point),
+                Some(SegmentIntersection::Overlap(overlap)) => {
+                    split_points.push(overlap.start);
+                    split_points.push(overlap.end);
+                }
+                None => {}
+            }
+        }
+
+        let direction = segment.direction();
+        let position = |point: Point| Vector::from(point - segment.start).dot_product(direction);
+        split_points.sort_by(|&first, &second| {
+            position(first)
+                .partial_cmp(&position(second))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let mut split_vertices: Vec<_> = split_points
+            .into_iter()
+            .map(|point| vertices.index_of(point))
+            .collect();
+        split_vertices.dedup();
+        edges.extend(
+            split_vertices
+                .windows(2)
+                .map(|pair| (pair[0], pair[1]))
+                .filter(|&(start, end)| start != end),
+        );
+    }
+    cancel_opposite_edges(edges)
+}
+
+/// Removes pairs of edges that connect the same vertices in opposite directions,
+/// as well as repeated edges
+fn cancel_opposite_edges(edges: Vec<Edge>) -> Vec<Edge> {
+    let mut remaining_edges: Vec<Edge> = Vec::with_capacity(edges.len());
+    for (start, end) in edges {
+        if let Some(position) = remaining_edges
+            .iter()
+            .position(|&edge| edge == (end, start))
+        {
+            remaining_edges.remove(position);
+        } else if !remaining_edges.contains(&(start, end)) {
+            remaining_edges.push((start, end));
+        }
+    }
+    remaining_edges
+}
+
+fn locate_edge(edge: Edge, other_edges: &[Edge], vertices: &Vertices) -> EdgeLocation {
+    let (start, end) = edge;
+    if other_edges.contains(&(start, end)) {
+        EdgeLocation::SharedWithSameDirection
+    } else if other_edges.contains(&(end, start)) {
+        EdgeLocation::SharedWithOppositeDirection
+    } else if winding_number(other_edges, vertices, vertices.segment(edge).midpoint()) != 0 {
+        EdgeLocation::Inside
+    } else {
+        EdgeLocation::Outside
+    }
+}
+
+/// Calculates how often the edges wind around `point`
+fn winding_number(edges: &[Edge], vertices: &Vertices, point: Point) -> i32 {
+    // See http://geomalgorithms.com/a03-_inclusion.html
+    edges
+        .iter()
+        .map(|&edge| {
+            let segment = vertices.segment(edge);
+            let is_left = segment
+                .direction()
+                .cross_product(Vector::from(point - segment.start));
+            if segment.start.y <= point.y && segment.end.y > point.y && is_left > 0.0 {
+                1
+            } else if segment.start.y > point.y && segment.end.y <= point.y && is_left < 0.0 {
+                -1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Links the edges into closed rings that don't visit any vertex twice.
+/// At every vertex, the walk continues along the edge that turns
+/// the furthest to the left, so that it follows the boundary of the area on its left.
+fn link_rings(edges: &[Edge], vertices: &Vertices) -> Vec<Vec<Point>> {
+    let mut outgoing_edges = vec![Vec::new(); vertices.points.len()];
+    for (index, &(start, _)) in edges.iter().enumerate() {
+        outgoing_edges[start].push(index);
+    }
+
+    let mut is_used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+    for first_edge in 0..edges.len() {
+        if is_used[first_edge] {
+            continue;
+        }
+        is_used[first_edge] = true;
+
+        let mut walk = vec![edges[first_edge].0];
+        let mut current_edge = first_edge;
+        let is_closed = loop {
+            let (start, end) = edges[current_edge];
+            let backwards = Vector::from(vertices.points[start] - vertices.points[end]);
+            let next_edge = outgoing_edges[end]
+                .iter()
+                .copied()
+                .filter(|&edge| !is_used[edge] || edge == first_edge)
+                .min_by(|&first, &second| {
+                    let turn = |edge: usize| {
+                        let (start, end) = edges[edge];
+                        clockwise_angle(
+                            backwards,
+                            Vector::from(vertices.points[end] - vertices.points[start]),
+                        )
+                    };
+                    turn(first)
+                        .partial_cmp(&turn(second))
+                        .unwrap_or(Ordering::Equal)
+                });
+
+            match next_edge {
+                Some(next_edge) if next_edge == first_edge => break true,
+                Some(next_edge) => {
+                    is_used[next_edge] = true;
+                    walk.push(end);
+                    current_edge = next_edge;
+                }
+                None => break false,
+            }
+        };
+
+        if is_closed {
+            rings.extend(split_at_repeated_vertices(&walk).into_iter().map(|ring| {
+                ring.into_iter()
+                    .map(|vertex| vertices.points[vertex])
+                    .collect()
+            }));
+        }
+    }
+    rings
+}
+
+/// Returns the angle by which `from` has to be rotated clockwise to point along `to`,
+/// in the range (0; 2π]
+fn clockwise_angle(from: Vector, to: Vector) -> f64 {
+    let angle = to.cross_product(from).atan2(from.dot_product(to));
+    if angle <= 0.0 {
+        angle + 2.0 * PI
+    } else {
+        angle
+    }
+}
+
+/// Splits a closed walk that passes some vertices more than once into simple rings
+fn split_at_repeated_vertices(walk: &[usize]) -> Vec<Vec<usize>> {
+    let mut rings = Vec::new();
+    let mut stack: Vec<usize> = Vec::with_capacity(walk.len());
+    for &vertex in walk {
+        if let Some(position) = stack.iter().position(|&visited| visited == vertex) {
+            rings.push(stack.split_off(position + 1));
+            rings.last_mut().unwrap().insert(0, vertex);
+        } else {
+            stack.push(vertex);
+        }
+    }
+    rings.push(stack);
+    rings
+}
+
+/// Groups rings into polygons. Counter clockwise rings become exteriors,
+/// and each clockwise ring becomes a hole of the smallest exterior surrounding it.
+fn assemble_polygons(rings: impl Iterator<Item = Vec<Point>>) -> MultiPolygon {
+    let (exteriors, holes): (Vec<_>, Vec<_>) = rings.partition(|ring| signed_area(ring) > 0.0);
+
+    let mut holes_of_exteriors = vec![Vec::new(); exteriors.len()];
+    for hole in holes {
+        let surrounding_exterior = exteriors
+            .iter()
+            .enumerate()
+            .filter(|(_, exterior)| {
+                hole.iter()
+                    .all(|&vertex| ring_contains_point(exterior, vertex))
+            })
+            .min_by(|(_, first), (_, second)| {
+                signed_area(first)
+                    .partial_cmp(&signed_area(second))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .map(|(index, _)| index);
+        if let Some(index) = surrounding_exterior {
+            holes_of_exteriors[index].push(SimplePolygon::new_unchecked(hole));
+        }
+    }
+
+    let polygons = exteriors
+        .into_iter()
+        .zip(holes_of_exteriors)
+        .map(|(exterior, holes)| {
+            PolygonWithHoles::new_unchecked(SimplePolygon::new_unchecked(exterior), holes)
+        })
+        .collect();
+    MultiPolygon { polygons }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Polygon, PolygonBuilder};
+    use nearly_eq::assert_nearly_eq;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        PolygonBuilder::default()
+            .vertex(x, y)
+            .vertex(x + size, y)
+            .vertex(x + size, y + size)
+            .vertex(x, y + size)
+            .build()
+            .unwrap()
+    }
+
+    fn region(x: f64, y: f64, size: f64) -> MultiPolygon {
+        MultiPolygon::from(square(x, y, size))
+    }
+
+    /// Checks that every polygon of the result passes validation
+    fn assert_valid(multi_polygon: &MultiPolygon) {
+        let polygons = multi_polygon
+            .polygons()
+            .iter()
+            .map(|polygon| {
+                let exterior = SimplePolygon::try_new(polygon.exterior().vertices().to_vec())
+                    .expect("Exterior is not simple");
+                let holes = polygon
+                    .holes()
+                    .iter()
+                    .map(|hole| {
+                        SimplePolygon::try_new(hole.vertices().to_vec())
+                            .expect("Hole is not simple")
+                    })
+                    .collect();
+                PolygonWithHoles::try_new(exterior, holes).expect("Holes are invalid")
+            })
+            .collect();
+        MultiPolygon::try_new(polygons).expect("Polygons overlap");
+    }
+
+    #[test]
+    fn operations_on_overlapping_squares() {
+        let first = region(0.0, 0.0, 10.0);
+        let second = region(5.0, 5.0, 10.0);
+
+        let union = first.union(&second);
+        let intersection = first.intersection(&second);
+        let difference = first.difference(&second);
+        let symmetric_difference = first.symmetric_difference(&second);
+
+        assert_nearly_eq!(175.0, union.area());
+        assert_nearly_eq!(25.0, intersection.area());
+        assert_nearly_eq!(75.0, difference.area());
+        assert_nearly_eq!(150.0, symmetric_difference.area());
+        assert_eq!(1, union.polygons().len());
+        assert_eq!(8, union.polygons()[0].exterior().vertices().len());
+        assert_eq!(2, symmetric_difference.polygons().len());
+        for result in &[union, intersection, difference, symmetric_difference] {
+            assert_valid(result);
+        }
+    }
+
+    #[test]
+    fn union_merges_polygons_sharing_an_edge() {
+        let union = region(0.0, 0.0, 10.0).union(&region(10.0, 0.0, 10.0));
+
+        assert_eq!(1, union.polygons().len());
+        assert_eq!(4, union.polygons()[0].exterior().vertices().len());
+        assert_nearly_eq!(200.0, union.area());
+    }
+
+    #[test]
+    fn union_merges_polygons_sharing_part_of_an_edge() {
+        let union = region(0.0, 0.0, 10.0).union(&region(10.0, 5.0, 10.0));
+
+        assert_eq!(1, union.polygons().len());
+        assert_eq!(8, union.polygons()[0].exterior().vertices().len());
+        assert_nearly_eq!(200.0, union.area());
+        assert_valid(&union);
+    }
+
+    #[test]
+    fn union_keeps_polygons_touching_at_a_vertex_separate() {
+        let union = region(0.0, 0.0, 10.0).union(&region(10.0, 10.0, 10.0));
+
+        assert_eq!(2, union.polygons().len());
+        assert_nearly_eq!(200.0, union.area());
+        assert_valid(&union);
+    }
+
+    #[test]
+    fn intersection_of_touching_polygons_is_empty() {
+        let first = region(0.0, 0.0, 10.0);
+
+        assert!(first.intersection(&region(10.0, 0.0, 10.0)).is_empty());
+        assert!(first.intersection(&region(10.0, 10.0, 10.0)).is_empty());
+    }
+
+    #[test]
+    fn operations_on_identical_polygons() {
+        let first = region(0.0, 0.0, 10.0);
+        let second = region(0.0, 0.0, 10.0);
+
+        assert_nearly_eq!(100.0, first.union(&second).area());
+        assert_nearly_eq!(100.0, first.intersection(&second).area());
+        assert!(first.difference(&second).is_empty());
+        assert!(first.symmetric_difference(&second).is_empty());
+    }
+
+    #[test]
+    fn difference_cuts_hole() {
+        let difference = region(0.0, 0.0, 10.0).difference(&region(4.0, 4.0, 2.0));
+
+        assert_eq!(1, difference.polygons().len());
+        assert_eq!(1, difference.polygons()[0].holes().len());
+        assert_nearly_eq!(96.0, difference.area());
+        assert!(!difference.contains_point(Point { x: 5.0, y: 5.0 }));
+        assert_valid(&difference);
+    }
+
+    #[test]
+    fn difference_cuts_hole_touching_exterior() {
+        let diamond = PolygonBuilder::default()
+            .vertex(0.0, 5.0)
+            .vertex(3.0, 2.0)
+            .vertex(6.0, 5.0)
+            .vertex(3.0, 8.0)
+            .build()
+            .unwrap();
+
+        let difference = region(0.0, 0.0, 10.0).difference(&MultiPolygon::from(diamond));
+
+        assert_eq!(1, difference.polygons().len());
+        assert_eq!(1, difference.polygons()[0].holes().len());
+        assert_nearly_eq!(100.0 - 18.0, difference.area());
+        assert_valid(&difference);
+    }
+
+    #[test]
+    fn difference_splits_polygon() {
+        let bar = PolygonBuilder::default()
+            .vertex(4.0, -5.0)
+            .vertex(6.0, -5.0)
+            .vertex(6.0, 15.0)
+            .vertex(4.0, 15.0)
+            .build()
+            .unwrap();
+
+        let difference = region(0.0, 0.0, 10.0).difference(&MultiPolygon::from(bar));
+
+        assert_eq!(2, difference.polygons().len());
+        assert_nearly_eq!(80.0, difference.area());
+        assert_valid(&difference);
+    }
+
+    #[test]
+    fn union_fills_hole() {
+        let ring = region(0.0, 0.0, 10.0).difference(&region(4.0, 4.0, 2.0));
+
+        let union = ring.union(&region(3.0, 3.0, 4.0));
+
+        assert_eq!(1, union.polygons().len());
+        assert!(union.polygons()[0].holes().is_empty());
+        assert_nearly_eq!(100.0, union.area());
+    }
+
+    #[test]
+    fn operations_on_concave_polygons() {
+        let u_shape = MultiPolygon::from(
+            SimplePolygon::try_new(vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 3.0, y: 0.0 },
+                Point { x: 3.0, y: 7.0 },
+                Point { x: 7.0, y: 7.0 },
+                Point { x: 7.0, y: 0.0 },
+                Point { x: 10.0, y: 0.0 },
+                Point { x: 10.0, y: 10.0 },
+                Point { x: 0.0, y: 10.0 },
+            ])
+            .unwrap(),
+        );
+        let lid = region(-1.0, -1.0, 12.0).difference(&region(-1.0, 2.0, 12.0));
+
+        let union = u_shape.union(&lid);
+        let intersection = u_shape.intersection(&lid);
+
+        assert_eq!(1, union.polygons().len());
+        assert_eq!(1, union.polygons()[0].holes().len());
+        assert_nearly_eq!(72.0 + 36.0 - 12.0, union.area());
+        assert_eq!(2, intersection.polygons().len());
+        assert_nearly_eq!(12.0, intersection.area());
+        assert_valid(&union);
+        assert_valid(&intersection);
+    }
+
+    #[test]
+    fn operations_with_empty_region() {
+        let square = region(0.0, 0.0, 10.0);
+        let empty = MultiPolygon::default();
+
+        assert_nearly_eq!(100.0, square.union(&empty).area());
+        assert!(square.intersection(&empty).is_empty());
+        assert_nearly_eq!(100.0, square.difference(&empty).area());
+        assert!(empty.difference(&square).is_empty());
+    }
+
+    #[test]
+    fn split_at_repeated_vertices_separates_loops() {
+        let rings = split_at_repeated_vertices(&[0, 1, 2, 3, 4, 5, 2, 6]);
+
+        assert_eq!(vec![vec![2, 3, 4, 5], vec![0, 1, 2, 6]], rings);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Vector;
+use crate::{Rotation, Support, Transform, Transformable, Vector};
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, Sub};
 
@@ -48,9 +48,51 @@ impl From<Vector> for Point {
     }
 }
 
+impl Point {
+    /// Rotates the point by a `rotation` around a `point`.
+    /// See [`Radians`] for the direction of the rotation.
+    /// Passing a [`Rotation`] instead of [`Radians`] skips computing its sine and cosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, Radians};
+    /// use nearly_eq::assert_nearly_eq;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let point = Point { x: 1.0, y: 0.0 };
+    /// let rotated_point = point.rotate_around_point(Radians::try_new(FRAC_PI_2).unwrap(), Point::default());
+    /// assert_nearly_eq!(0.0, rotated_point.x);
+    /// assert_nearly_eq!(-1.0, rotated_point.y);
+    /// ```
+    ///
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    /// [`Rotation`]: ./struct.Rotation.html
+    pub fn rotate_around_point<R>(self, rotation: R, point: Point) -> Point
+    where
+        R: Into<Rotation>,
+    {
+        rotation.into().rotate_point_around_point(self, point)
+    }
+}
+
+impl Support for Point {
+    fn support_point(&self, _direction: Vector) -> Point {
+        *self
+    }
+}
+
+impl Transformable for Point {
+    fn transform(&self, transform: Transform) -> Self {
+        transform.map_point(*self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Radians;
+    use nearly_eq::assert_nearly_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
 
     #[test]
     #[allow(clippy::eq_op)]
@@ -162,4 +204,39 @@ mod tests {
         let substracted_point = original_point - point_to_subtract;
         assert_eq!(expected_point, substracted_point);
     }
+
+    #[test]
+    fn rotates_positive_x_axis_towards_negative_y_axis() {
+        let point = Point { x: 1.0, y: 0.0 };
+
+        let rotated_point =
+            point.rotate_around_point(Radians::try_new(FRAC_PI_2).unwrap(), Point::default());
+
+        assert_nearly_eq!(0.0, rotated_point.x);
+        assert_nearly_eq!(-1.0, rotated_point.y);
+    }
+
+    #[test]
+    fn rotates_around_point() {
+        let point = Point { x: 5.0, y: 3.0 };
+        let center = Point { x: 2.0, y: 3.0 };
+
+        let rotated_point = point.rotate_around_point(Radians::try_new(PI).unwrap(), center);
+
+        assert_nearly_eq!(-1.0, rotated_point.x);
+        assert_nearly_eq!(3.0, rotated_point.y);
+    }
+
+    #[test]
+    fn rotation_matches_transform() {
+        let point = Point { x: 5.0, y: -3.0 };
+        let rotation = Radians::try_new(2.0).unwrap();
+        let center = Point { x: 1.0, y: 1.0 };
+
+        let rotated_point = point.rotate_around_point(rotation, center);
+        let transformed_point = point.transform(Transform::rotation_around_point(rotation, center));
+
+        assert_nearly_eq!(transformed_point.x, rotated_point.x);
+        assert_nearly_eq!(transformed_point.y, rotated_point.y);
+    }
 }
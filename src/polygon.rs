@@ -1,48 +1,95 @@
 //! Types relating to 2D convex polygons and their construction
 
 pub use self::builder::*;
+pub use self::time_of_impact::{Impact, Motion};
 use super::*;
-use crate::ConvexHull;
+use crate::convex_hull::cross_product;
+use crate::simple_polygon::ring_segments;
 use crate::Intersects;
-use itertools::Itertools;
+use crate::{CollinearPoints, ConvexHull};
+use itertools::{Either, Itertools};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt;
 
 mod builder;
+mod clipping;
+mod contacts;
+mod minkowski;
+mod time_of_impact;
+
+pub(crate) use self::clipping::{
+    clip_to_half_plane, polygon_from_clipped_vertices, remove_redundant_vertices,
+};
 
 /// A convex polygon.
 ///
 /// Can either be constructed using a [`PolygonBuilder`]
 /// or with [`Polygon::try_new`].
 ///
+/// Deserialization goes through [`Polygon::try_new`] as well,
+/// so invalid vertices are rejected.
+///
 /// [`PolygonBuilder`]: ./struct.PolygonBuilder.html
 /// [`Polygon::try_new`]: ./struct.Polygon.html#method.try_new
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedPolygon")]
 pub struct Polygon {
     /// The vertices of the polygon
     vertices: Vec<Point>,
 }
 
+/// Mirrors the serialized representation of a [`Polygon`]
+/// before its vertices have been validated
+#[derive(Deserialize)]
+#[serde(rename = "Polygon")]
+struct UncheckedPolygon {
+    vertices: Vec<Point>,
+}
+
+impl TryFrom<UncheckedPolygon> for Polygon {
+    type Error = PolygonError;
+
+    fn try_from(unchecked: UncheckedPolygon) -> Result<Self, Self::Error> {
+        Polygon::try_new(unchecked.vertices)
+    }
+}
+
 impl Polygon {
     /// Creates a new [`Polygon`] from the given [`Point`]s.
     ///
     /// # Errors
-    /// This method will return an error if the number of configured
+    /// This method will return a [`PolygonError`] if the number of configured
     /// vertices is less than three (as the resulting [`Polygon`]
-    /// would not be two-dimensional), or if the resulting [`Polygon`] is not convex.
+    /// would not be two-dimensional), if any vertex is `NaN` or infinite,
+    /// or if the resulting [`Polygon`] is not convex.
+    /// Vertices that lie on an edge between two corners are allowed,
+    /// as long as the vertices don't all lie on a single line.
     ///
     /// [`Polygon`]: ./struct.Polygon.html
     /// [`Point`]: ./struct.Point.html
-    pub fn try_new(vertices: Vec<Point>) -> Result<Self, ()> {
+    /// [`PolygonError`]: ./enum.PolygonError.html
+    pub fn try_new(vertices: Vec<Point>) -> Result<Self, PolygonError> {
         const MINIMUM_VERTICES_IN_EUCLIDEAN_GEOMETRY: usize = 3;
 
-        if vertices.len() >= MINIMUM_VERTICES_IN_EUCLIDEAN_GEOMETRY
-            && vertices.iter().all(is_finite)
-            && is_convex_polygon(&vertices)
-        {
-            Ok(Self { vertices })
-        } else {
-            Err(())
+        if vertices.len() < MINIMUM_VERTICES_IN_EUCLIDEAN_GEOMETRY {
+            return Err(PolygonError::TooFewVertices {
+                count: vertices.len(),
+            });
+        }
+
+        if let Some(index) = vertices.iter().position(|vertex| !is_finite(vertex)) {
+            return Err(PolygonError::NonFiniteVertex { index });
+        }
+
+        if let Some(index) = find_vertex_violating_convexity(&vertices) {
+            return Err(PolygonError::NotConvex { index });
         }
+
+        Ok(Self { vertices })
     }
 
     /// Returns the vertices of the polygon
@@ -64,52 +111,35 @@ impl Polygon {
         }
     }
 
-    /// Rotate polygon by a `rotation` around a `point`
-    pub fn rotate_around_point(&self, rotation: Radians, point: Point) -> Self {
-        let rotation = rotation.value();
+    /// Rotate polygon by a `rotation` around a `point`.
+    /// See [`Radians`] for the direction of the rotation.
+    ///
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    pub fn rotate_around_point<R>(&self, rotation: R, point: Point) -> Self
+    where
+        R: Into<Rotation>,
+    {
+        let rotation = rotation.into();
         let rotated_vertices = self
             .vertices
             .iter()
-            .map(|&vertex| {
-                // See https://en.wikipedia.org/wiki/Rotation_matrix
-                let delta = vertex - point;
-                let (rotation_sin, rotation_cos) = rotation.sin_cos();
-                let rotated_x = rotation_cos * delta.x + rotation_sin * delta.y + point.x;
-                let rotated_y = -rotation_sin * delta.x + rotation_cos * delta.y + point.y;
-                Point {
-                    x: rotated_x,
-                    y: rotated_y,
-                }
-            })
+            .map(|&vertex| vertex.rotate_around_point(rotation, point))
             .collect();
         Self {
             vertices: rotated_vertices,
         }
     }
 
-    /// Checks if a given point rests inside the polygon
+    /// Checks if a given point rests inside the polygon or on its boundary,
+    /// regardless of the order in which the vertices are stored
     pub fn contains_point(&self, point: Point) -> bool {
-        let vector_to_point: Vector = point.into();
-        // The following unwraps are safe, as we do an
-        // early return if we don't contain at least 2 vertices
-        let vector_to_last_point: Vector = (*self.vertices.last().unwrap()).into();
-        let vector_to_first_point: Vector = (*self.vertices.first().unwrap()).into();
-        let reference_side =
-            calculate_facing_side(vector_to_last_point, vector_to_first_point, vector_to_point);
-
-        // If the point lies on the same side of all lines of the polygon,
-        // the point is contained in the polygon.
-        self.vertices
-            .iter()
-            .tuple_windows()
-            .map(|(&vector_to_point_a, &vector_to_point_b)| {
-                calculate_facing_side(
-                    vector_to_point_a.into(),
-                    vector_to_point_b.into(),
-                    vector_to_point,
-                )
+        // The edges are directed counter clockwise, so the point is contained
+        // in the polygon if it doesn't lie on the clockwise side of any of them
+        !self.vertices.is_empty()
+            && self.counter_clockwise_segments().all(|segment| {
+                calculate_facing_side(segment.start.into(), segment.end.into(), point.into())
+                    != Side::Left
             })
-            .all(|side| side == reference_side || side == Side::OnTheLine)
     }
 
     /// Returns an [`Aabb`] which fully contains this polygon.
@@ -119,20 +149,7 @@ impl Polygon {
     /// are not comparable, e.g. `NaN` or if the polygon has no vertices.
     /// The latter should never occur, because the constructor validates that the polygon is valid.
     pub fn aabb(&self) -> Aabb {
-        let mut vertices = self.vertices.clone();
-
-        // Safe unwrap: A polygon's vertex should not be baloney like NaN
-        vertices.sort_unstable_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
-        // Safe unwraps: A polygon should always have at least one vertex
-        let min_x = vertices.first().unwrap().x;
-        let max_x = vertices.last().unwrap().x;
-
-        vertices.sort_unstable_by(|a, b| a.y.partial_cmp(&b.y).unwrap());
-        let min_y = vertices.first().unwrap().y;
-        let max_y = vertices.last().unwrap().y;
-
-        // Safe unwrap: A polygon where all four points are the same is not valid
-        Aabb::try_new((min_x, min_y), (max_x, max_y)).unwrap()
+        bounding_box(&self.vertices)
     }
 
     /// Returns the polygon's edges, i.e. the lines between vertices, as vectors.
@@ -146,6 +163,80 @@ impl Polygon {
             .map(Vector::from)
     }
 
+    /// Returns the polygon's edges as positioned [`Segment`]s,
+    /// in the same order as [`Polygon::edges`].
+    ///
+    /// [`Segment`]: ./struct.Segment.html
+    /// [`Polygon::edges`]: ./struct.Polygon.html#method.edges
+    pub fn segments(&self) -> impl Iterator<Item = Segment> + '_ {
+        let vertices = self.vertices();
+        let shifted_vertices = vertices.iter().cycle().skip(1).take(vertices.len());
+        vertices
+            .iter()
+            .zip(shifted_vertices)
+            .map(|(&start, &end)| Segment { start, end })
+    }
+
+    /// Calculates how this polygon and `other` penetrate each other.
+    ///
+    /// The returned [`Manifold`]'s normal points from this polygon towards `other`.
+    /// Returns `None` if the polygons don't [intersect].
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, Polygon, Vector};
+    ///
+    /// let left = Polygon::try_new(vec![
+    ///     Point { x: 0.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 10.0 },
+    ///     Point { x: 0.0, y: 10.0 },
+    /// ])
+    /// .unwrap();
+    /// let right = left.translate(Point { x: 8.0, y: 0.0 });
+    ///
+    /// let manifold = left.collision_manifold(&right).unwrap();
+    /// assert_eq!(Vector { x: 2.0, y: 0.0 }, manifold.minimum_translation_vector());
+    /// ```
+    ///
+    /// [`Manifold`]: ./struct.Manifold.html
+    /// [intersect]: ./trait.Intersects.html
+    pub fn collision_manifold(&self, other: &Polygon) -> Option<Manifold> {
+        let mut manifold: Option<Manifold> = None;
+
+        for axis in self.separating_axis_candidates(other) {
+            let (own_min, own_max) = self.scalar_project_onto_unit_vector(axis);
+            let (other_min, other_max) = other.scalar_project_onto_unit_vector(axis);
+
+            // Distances that `other` would have to travel along the axis
+            // in either direction in order to stop overlapping
+            let depth_along_axis = own_max - other_min;
+            let depth_against_axis = other_max - own_min;
+            if depth_along_axis < 0.0 || depth_against_axis < 0.0 {
+                return None;
+            }
+
+            let candidate = if depth_along_axis <= depth_against_axis {
+                Manifold {
+                    normal: axis,
+                    depth: depth_along_axis,
+                }
+            } else {
+                Manifold {
+                    normal: axis.negative(),
+                    depth: depth_against_axis,
+                }
+            };
+
+            match manifold {
+                Some(current) if current.depth <= candidate.depth => {}
+                _ => manifold = Some(candidate),
+            }
+        }
+
+        manifold
+    }
+
     fn scalar_project_onto_unit_vector(&self, axis: Vector) -> (f64, f64) {
         let projection: Vec<_> = self
             .vertices()
@@ -165,15 +256,114 @@ impl Polygon {
                 .unwrap(),
         )
     }
-}
 
-impl Intersects for Polygon {
-    /// Returns wether this polygon touches, contains or is contained in another polygon
-    fn intersects(&self, other: &Polygon) -> bool {
-        // The following codes describes the Separating Axis Theorem (SAT),
-        // which states that if we are able to draw a straight line (i.e. axis)
-        // between two polygons (i.e. separating them), they are not intersecting
+    /// Returns the indices of the vertices, ordered counter clockwise
+    pub(crate) fn counter_clockwise_vertex_indices(&self) -> Vec<usize> {
+        let count = self.vertices.len();
+        match self.stored_winding() {
+            Some(Winding::CounterClockwise) => (0..count).collect(),
+            Some(Winding::Clockwise) => (0..count).rev().collect(),
+            None => self.convex_hull_vertex_indices(),
+        }
+    }
+
+    /// Returns the vertices, ordered counter clockwise
+    pub(crate) fn counter_clockwise_vertices(&self) -> Vec<Point> {
+        self.counter_clockwise_vertex_indices()
+            .into_iter()
+            .map(|index| self.vertices[index])
+            .collect()
+    }
+
+    /// Returns the boundary of the polygon, with every edge directed counter clockwise
+    pub(crate) fn counter_clockwise_segments(&self) -> impl Iterator<Item = Segment> + '_ {
+        match self.stored_winding() {
+            Some(winding) => Either::Left(ring_segments(&self.vertices).map(move |segment| {
+                if winding == Winding::Clockwise {
+                    Segment {
+                        start: segment.end,
+                        end: segment.start,
+                    }
+                } else {
+                    segment
+                }
+            })),
+            None => {
+                let vertices = self.counter_clockwise_vertices();
+                let segments: Vec<_> = ring_segments(&vertices).collect();
+                Either::Right(segments.into_iter())
+            }
+        }
+    }
+
+    /// Returns the winding of the stored vertices if they already form a convex ring,
+    /// which is the case unless they were given out of order.
+    /// Runs in O(n) time and doesn't allocate.
+    fn stored_winding(&self) -> Option<Winding> {
+        let mut winding = None;
+        let mut total_turn = 0.0;
+        for (&previous, &current, &next) in self.vertices.iter().circular_tuple_windows() {
+            let incoming = Vector::from(current - previous);
+            let outgoing = Vector::from(next - current);
+            let cross_product = incoming.cross_product(outgoing);
+            let dot_product = incoming.dot_product(outgoing);
+            if cross_product == 0.0 {
+                // Vertices on an edge go straight on, while turning back
+                // means the vertices are out of order
+                if dot_product <= 0.0 {
+                    return None;
+                }
+                continue;
+            }
+
+            let turn = if cross_product > 0.0 {
+                Winding::CounterClockwise
+            } else {
+                Winding::Clockwise
+            };
+            if *winding.get_or_insert(turn) != turn {
+                return None;
+            }
+            total_turn += cross_product.atan2(dot_product);
+        }
+
+        // A ring that turns the same way at every vertex can still
+        // wind around more than once, like a pentagram
+        if total_turn.abs() < 3.0 * PI {
+            winding
+        } else {
+            None
+        }
+    }
+
+    /// Sorts the indices of vertices that were stored out of order along their convex hull.
+    /// Runs in O(n log n) time.
+    fn convex_hull_vertex_indices(&self) -> Vec<usize> {
+        let compare =
+            |first: &Point, second: &Point| first.partial_cmp(second).unwrap_or(Ordering::Equal);
+        let mut sorted_indices: Vec<_> = (0..self.vertices.len()).collect();
+        sorted_indices
+            .sort_by(|&first, &second| compare(&self.vertices[first], &self.vertices[second]));
+
+        ConvexHull::try_with_collinear_points(&self.vertices, CollinearPoints::Keep)
+            .map(|convex_hull| {
+                convex_hull
+                    .filter_map(|hull_point| {
+                        sorted_indices
+                            .binary_search_by(|&index| compare(&self.vertices[index], &hull_point))
+                            .ok()
+                            .map(|position| sorted_indices[position])
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
+    /// Returns the axes that need to be checked by the Separating Axis Theorem
+    fn separating_axis_candidates<'a>(
+        &'a self,
+        other: &'a Polygon,
+    ) -> impl Iterator<Item = Vector> + 'a {
         // Take all edges
         self.edges()
             .chain(other.edges())
@@ -184,16 +374,107 @@ impl Intersects for Polygon {
             // If the axis has a magnitude of 1, we don't need to divide
             // the scalar projection by it.
             .map(Vector::unit)
-            .all(|axis| {
-                // Take the bounds of the line that is created by projecting all
-                // vertices onto the axis
-                let (own_min, own_max) = self.scalar_project_onto_unit_vector(axis);
-                let (other_min, other_max) = other.scalar_project_onto_unit_vector(axis);
-
-                // If both bounds are outside the other polygon's projection, we are
-                // able to draw a separating axis between them
-                own_min.max(other_min) <= own_max.min(other_max)
+    }
+}
+
+impl Intersects for Polygon {
+    /// Returns wether this polygon touches, contains or is contained in another polygon
+    fn intersects(&self, other: &Polygon) -> bool {
+        // The following codes describes the Separating Axis Theorem (SAT),
+        // which states that if we are able to draw a straight line (i.e. axis)
+        // between two polygons (i.e. separating them), they are not intersecting
+        self.separating_axis_candidates(other).all(|axis| {
+            // Take the bounds of the line that is created by projecting all
+            // vertices onto the axis
+            let (own_min, own_max) = self.scalar_project_onto_unit_vector(axis);
+            let (other_min, other_max) = other.scalar_project_onto_unit_vector(axis);
+
+            // If both bounds are outside the other polygon's projection, we are
+            // able to draw a separating axis between them
+            own_min.max(other_min) <= own_max.min(other_max)
+        })
+    }
+}
+
+impl Transformable for Polygon {
+    /// Transforms every vertex. As transforms can't scale by zero, the polygon stays convex,
+    /// while its area is multiplied by the absolute value of the transform's determinant.
+    /// See [`Transformable`] for the limits imposed by rounding and overflow.
+    ///
+    /// [`Transformable`]: ./trait.Transformable.html
+    fn transform(&self, transform: Transform) -> Self {
+        Self {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|&vertex| transform.map_point(vertex))
+                .collect(),
+        }
+    }
+}
+
+impl Support for Polygon {
+    fn support_point(&self, direction: Vector) -> Point {
+        self.vertices
+            .iter()
+            .copied()
+            .max_by(|&first, &second| {
+                Vector::from(first)
+                    .dot_product(direction)
+                    .partial_cmp(&Vector::from(second).dot_product(direction))
+                    .unwrap_or(std::cmp::Ordering::Equal)
             })
+            .unwrap_or_default()
+    }
+}
+
+impl RayCast for Polygon {
+    /// Casts a ray against this polygon by clipping it against
+    /// the half-planes of all edges (Cyrus–Beck)
+    fn cast_ray(&self, ray: &Ray, max_distance: Option<f64>) -> Option<RayHit> {
+        let mut entry_time = f64::NEG_INFINITY;
+        let mut exit_time = f64::INFINITY;
+        let mut entry_normal = Vector::default();
+
+        for edge in self.counter_clockwise_segments() {
+            // The vertices are ordered counter clockwise, so the
+            // left-hand normal points into the polygon
+            let outward_normal = edge.direction().normal().negative().unit();
+            let distance_to_edge =
+                outward_normal.dot_product(Vector::from(edge.start - ray.origin));
+            let approach_speed = outward_normal.dot_product(ray.direction);
+
+            if approach_speed == 0.0 {
+                // The ray runs parallel to this edge, so it never
+                // crosses it if it starts on its outer side
+                if distance_to_edge < 0.0 {
+                    return None;
+                }
+                continue;
+            }
+
+            let time_of_crossing = distance_to_edge / approach_speed;
+            if approach_speed < 0.0 {
+                if time_of_crossing > entry_time {
+                    entry_time = time_of_crossing;
+                    entry_normal = outward_normal;
+                }
+            } else {
+                exit_time = exit_time.min(time_of_crossing);
+            }
+
+            if entry_time > exit_time {
+                return None;
+            }
+        }
+
+        if exit_time < 0.0 {
+            None
+        } else if entry_time < 0.0 {
+            Some(RayHit::at_origin(ray))
+        } else {
+            RayHit::try_new(ray, entry_time, entry_normal, max_distance)
+        }
     }
 }
 
@@ -222,6 +503,29 @@ impl From<Aabb> for Polygon {
     }
 }
 
+/// Returns the smallest [`Aabb`] containing all `points`.
+///
+/// # Panics
+/// Panics if the floating-point values representing the points' coordinates
+/// are not comparable, e.g. `NaN`, if no points are given
+/// or if all points lie on a horizontal or vertical line.
+pub(crate) fn bounding_box(points: &[Point]) -> Aabb {
+    let mut points = points.to_vec();
+
+    // Safe unwrap: Points should not be baloney like NaN
+    points.sort_unstable_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+    // Safe unwraps: Polygons always have at least one vertex
+    let min_x = points.first().unwrap().x;
+    let max_x = points.last().unwrap().x;
+
+    points.sort_unstable_by(|a, b| a.y.partial_cmp(&b.y).unwrap());
+    let min_y = points.first().unwrap().y;
+    let max_y = points.last().unwrap().y;
+
+    // Safe unwrap: Polygons whose vertices all lie on a line are not valid
+    Aabb::try_new((min_x, min_y), (max_x, max_y)).unwrap()
+}
+
 /// Calculate which on which side of a line from `a` to `b` a
 /// given `point` is
 fn calculate_facing_side(a: Vector, b: Vector, point: Vector) -> Side {
@@ -241,15 +545,93 @@ fn calculate_facing_side(a: Vector, b: Vector, point: Vector) -> Side {
     }
 }
 
-fn is_convex_polygon(vertices: &[Point]) -> bool {
-    let convex_hull_vertice_count = ConvexHull::try_new(vertices).unwrap().count();
-    convex_hull_vertice_count == vertices.len()
+/// Returns the index of the first vertex that is not part of the
+/// convex hull of all vertices, if any.
+/// Vertices that lie on an edge between two corners of the hull are allowed.
+fn find_vertex_violating_convexity(vertices: &[Point]) -> Option<usize> {
+    let convex_hull: Vec<_> =
+        ConvexHull::try_with_collinear_points(vertices, CollinearPoints::Keep)
+            .unwrap()
+            .collect();
+    if convex_hull.len() == vertices.len() {
+        // If all vertices lie on a single line, the hull consists of the sorted
+        // vertices and encloses no area. Only its two ends could be corners.
+        let first = convex_hull[0];
+        let last = convex_hull[convex_hull.len() - 1];
+        let is_line = convex_hull
+            .iter()
+            .all(|&vertex| cross_product(first, last, vertex) == 0.0);
+        if is_line {
+            vertices
+                .iter()
+                .position(|&vertex| vertex != first && vertex != last)
+        } else {
+            None
+        }
+    } else {
+        vertices
+            .iter()
+            .position(|vertex| !convex_hull.contains(vertex))
+            // All vertices are part of the hull, but some of them are duplicates
+            .or_else(|| {
+                vertices
+                    .iter()
+                    .enumerate()
+                    .position(|(index, vertex)| vertices[..index].contains(vertex))
+            })
+    }
 }
 
 fn is_finite(vertice: &Point) -> bool {
     vertice.x.is_finite() && vertice.y.is_finite()
 }
 
+/// The reason why a [`Polygon`] could not be created
+///
+/// [`Polygon`]: ./struct.Polygon.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PolygonError {
+    /// Fewer than three vertices were given
+    TooFewVertices {
+        /// The number of vertices that were given
+        count: usize,
+    },
+    /// A vertex contained a coordinate that was `NaN` or infinite
+    NonFiniteVertex {
+        /// The index of the offending vertex
+        index: usize,
+    },
+    /// The vertices did not form a convex polygon
+    NotConvex {
+        /// The index of the first vertex that is not part of the convex hull
+        index: usize,
+    },
+}
+
+impl fmt::Display for PolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolygonError::TooFewVertices { count } => write!(
+                f,
+                "A polygon requires at least three vertices, but {} were given",
+                count
+            ),
+            PolygonError::NonFiniteVertex { index } => write!(
+                f,
+                "Vertex at index {} has a coordinate that is NaN or infinite",
+                index
+            ),
+            PolygonError::NotConvex { index } => write!(
+                f,
+                "Polygon is not convex: vertex at index {} is not part of its convex hull",
+                index
+            ),
+        }
+    }
+}
+
+impl Error for PolygonError {}
+
 /// The side that a [`Point`] lies on, from the
 /// point of view of a line
 #[derive(Eq, PartialEq, Debug)]
@@ -266,6 +648,8 @@ enum Side {
 mod tests {
     use self::builder::PolygonBuilder;
     use super::*;
+    use nearly_eq::assert_nearly_eq;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
     use std::f64::consts::PI;
 
     fn polygon() -> Polygon {
@@ -360,6 +744,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rotates_edges_like_vectors() {
+        let polygon = polygon();
+        let rotation = Radians::try_new(1.0).unwrap();
+
+        let rotated_polygon = polygon.rotate_around_point(rotation, Point { x: 3.0, y: 4.0 });
+
+        for (edge, rotated_edge) in polygon.segments().zip(rotated_polygon.segments()) {
+            let expected_edge = Vector::from(edge.end - edge.start).rotate(rotation);
+            let rotated_edge = Vector::from(rotated_edge.end - rotated_edge.start);
+            assert_nearly_eq!(expected_edge.x, rotated_edge.x);
+            assert_nearly_eq!(expected_edge.y, rotated_edge.y);
+        }
+    }
+
     #[test]
     fn translates_and_rotates() {
         let polygon = polygon();
@@ -446,6 +845,109 @@ mod tests {
         assert!(!polygon.contains_point(point));
     }
 
+    #[test]
+    fn contains_point_on_every_edge() {
+        let polygon = polygon();
+        for &point in &[
+            Point { x: 0.0, y: -10.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+            Point { x: -10.0, y: 0.0 },
+        ] {
+            assert!(polygon.contains_point(point));
+        }
+    }
+
+    #[test]
+    fn contains_point_regardless_of_vertex_order() {
+        let polygon = Polygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap();
+
+        assert!(polygon.contains_point(Point { x: 2.0, y: 5.0 }));
+        assert!(!polygon.contains_point(Point { x: 11.0, y: 5.0 }));
+    }
+
+    #[test]
+    fn does_not_contain_point_when_polygon_has_zero_vertices() {
+        assert!(!Polygon::default().contains_point(Point::default()));
+    }
+
+    #[test]
+    fn counter_clockwise_vertex_indices_keep_counter_clockwise_order() {
+        assert_eq!(
+            vec![0, 1, 2, 3],
+            polygon().counter_clockwise_vertex_indices()
+        );
+    }
+
+    #[test]
+    fn counter_clockwise_vertex_indices_reverse_clockwise_order() {
+        let vertices = polygon().vertices().iter().rev().cloned().collect();
+        let polygon = Polygon::try_new(vertices).unwrap();
+
+        assert_eq!(vec![3, 2, 1, 0], polygon.counter_clockwise_vertex_indices());
+    }
+
+    #[test]
+    fn counter_clockwise_vertex_indices_keep_vertices_on_edges() {
+        let polygon = Polygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 5.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap();
+
+        assert_eq!(
+            vec![0, 1, 2, 3, 4],
+            polygon.counter_clockwise_vertex_indices()
+        );
+    }
+
+    #[test]
+    fn counter_clockwise_vertex_indices_sort_vertices_out_of_order() {
+        let polygon = Polygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap();
+
+        assert_eq!(vec![0, 2, 1, 3], polygon.counter_clockwise_vertex_indices());
+    }
+
+    #[test]
+    fn counter_clockwise_vertex_indices_sort_vertices_winding_twice() {
+        let corners: Vec<_> = (0..5)
+            .map(|corner| {
+                let angle = f64::from(corner) * 2.0 * PI / 5.0;
+                Point {
+                    x: angle.cos(),
+                    y: angle.sin(),
+                }
+            })
+            .collect();
+        let pentagram = Polygon::try_new(
+            [0, 2, 4, 1, 3]
+                .iter()
+                .map(|&corner| corners[corner])
+                .collect(),
+        )
+        .unwrap();
+
+        let mut indices = pentagram.counter_clockwise_vertex_indices();
+        let first = indices.iter().position(|&index| index == 0).unwrap();
+        indices.rotate_left(first);
+        assert_eq!(vec![0, 3, 1, 4, 2], indices);
+    }
+
     #[test]
     #[should_panic]
     fn aabb_panics_when_polygon_has_zero_vertices() {
@@ -494,8 +996,9 @@ mod tests {
 
     #[test]
     fn try_new_errors_for_two_vertices() {
-        assert!(
-            Polygon::try_new(vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 0.0 }]).is_err()
+        assert_eq!(
+            Err(PolygonError::TooFewVertices { count: 2 }),
+            Polygon::try_new(vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 0.0 }])
         );
     }
 
@@ -539,14 +1042,16 @@ mod tests {
 
     #[test]
     fn try_new_does_not_work_with_concave_polygon() {
-        assert!(Polygon::try_new(vec![
-            Point { x: 10.0, y: 10.0 },
-            Point { x: 5.0, y: 5.0 },
-            Point { x: 10.0, y: 5.0 },
-            Point { x: 15.0, y: 0.0 },
-            Point { x: 10.0, y: 0.0 },
-        ])
-        .is_err());
+        assert_eq!(
+            Err(PolygonError::NotConvex { index: 2 }),
+            Polygon::try_new(vec![
+                Point { x: 10.0, y: 10.0 },
+                Point { x: 5.0, y: 5.0 },
+                Point { x: 10.0, y: 5.0 },
+                Point { x: 15.0, y: 0.0 },
+                Point { x: 10.0, y: 0.0 },
+            ])
+        );
     }
 
     #[test]
@@ -567,6 +1072,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_new_works_with_vertex_on_edge() {
+        let vertices = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 5.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+        ];
+
+        assert_eq!(
+            Ok(Polygon {
+                vertices: vertices.clone(),
+            }),
+            Polygon::try_new(vertices)
+        );
+    }
+
+    #[test]
+    fn try_new_does_not_work_with_collinear_vertices() {
+        assert_eq!(
+            Err(PolygonError::NotConvex { index: 2 }),
+            Polygon::try_new(vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 10.0, y: 0.0 },
+                Point { x: 5.0, y: 0.0 },
+            ])
+        );
+    }
+
     #[test]
     fn try_new_does_not_work_when_x_value_of_vertice_is_positive_infinity() {
         let vertices = vec![
@@ -606,6 +1141,36 @@ mod tests {
         assert!(Polygon::try_new(vertices).is_err());
     }
 
+    #[test]
+    fn try_new_reports_index_of_non_finite_vertex() {
+        let vertices = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point {
+                x: 0.0,
+                y: f64::NAN,
+            },
+        ];
+        assert_eq!(
+            Err(PolygonError::NonFiniteVertex { index: 2 }),
+            Polygon::try_new(vertices)
+        );
+    }
+
+    #[test]
+    fn try_new_reports_duplicate_vertex_as_not_convex() {
+        let vertices = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 0.0, y: 1.0 },
+            Point { x: 1.0, y: 0.0 },
+        ];
+        assert_eq!(
+            Err(PolygonError::NotConvex { index: 3 }),
+            Polygon::try_new(vertices)
+        );
+    }
+
     #[test]
     fn try_new_does_not_work_when_y_value_of_vertice_is_positive_infinity() {
         let vertices = vec![
@@ -680,6 +1245,25 @@ mod tests {
         assert_eq!(expected_edges, edges);
     }
 
+    #[test]
+    fn segments_are_reported_correctly() {
+        let polygon = Polygon::try_new(vec![
+            Point { x: 10.0, y: 15.0 },
+            Point { x: 20.0, y: 15.0 },
+            Point { x: 20.0, y: 30.0 },
+        ])
+        .unwrap();
+
+        let expected_segments = vec![
+            Segment::new((10.0, 15.0), (20.0, 15.0)),
+            Segment::new((20.0, 15.0), (20.0, 30.0)),
+            Segment::new((20.0, 30.0), (10.0, 15.0)),
+        ];
+
+        let segments: Vec<_> = polygon.segments().collect();
+        assert_eq!(expected_segments, segments);
+    }
+
     #[test]
     fn intersects_self() {
         let polygon = Polygon::try_new(vec![
@@ -816,4 +1400,193 @@ mod tests {
         assert!(!first_polygon.intersects(&second_polygon));
         assert!(!second_polygon.intersects(&first_polygon));
     }
+
+    fn polygon_tokens(vertices: &[(f64, f64)]) -> Vec<Token> {
+        let mut tokens = vec![
+            Token::Struct {
+                name: "Polygon",
+                len: 1,
+            },
+            Token::Str("vertices"),
+            Token::Seq {
+                len: Some(vertices.len()),
+            },
+        ];
+        for &(x, y) in vertices {
+            tokens.extend_from_slice(&[
+                Token::Struct {
+                    name: "Point",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::F64(x),
+                Token::Str("y"),
+                Token::F64(y),
+                Token::StructEnd,
+            ]);
+        }
+        tokens.extend_from_slice(&[Token::SeqEnd, Token::StructEnd]);
+        tokens
+    }
+
+    #[test]
+    fn serializes_and_deserializes_valid_polygon() {
+        let polygon = polygon();
+        assert_tokens(
+            &polygon,
+            &polygon_tokens(&[(-10.0, -10.0), (10.0, -10.0), (10.0, 10.0), (-10.0, 10.0)]),
+        );
+    }
+
+    #[test]
+    fn deserialization_errors_for_concave_polygon() {
+        assert_de_tokens_error::<Polygon>(
+            &polygon_tokens(&[
+                (10.0, 10.0),
+                (5.0, 5.0),
+                (10.0, 5.0),
+                (15.0, 0.0),
+                (10.0, 0.0),
+            ]),
+            &PolygonError::NotConvex { index: 2 }.to_string(),
+        );
+    }
+
+    #[test]
+    fn deserialization_errors_for_too_few_vertices() {
+        assert_de_tokens_error::<Polygon>(
+            &polygon_tokens(&[(0.0, 0.0), (1.0, 0.0)]),
+            &PolygonError::TooFewVertices { count: 2 }.to_string(),
+        );
+    }
+
+    fn square_at(x: f64, y: f64) -> Polygon {
+        PolygonBuilder::default()
+            .vertex(x, y)
+            .vertex(x + 10.0, y)
+            .vertex(x + 10.0, y + 10.0)
+            .vertex(x, y + 10.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn collision_manifold_is_none_when_apart() {
+        let first_square = square_at(0.0, 0.0);
+        let second_square = square_at(20.0, 0.0);
+        assert_eq!(None, first_square.collision_manifold(&second_square));
+        assert_eq!(None, second_square.collision_manifold(&first_square));
+    }
+
+    #[test]
+    fn collision_manifold_has_zero_depth_when_touching() {
+        let first_square = square_at(0.0, 0.0);
+        let second_square = square_at(10.0, 0.0);
+        let manifold = first_square.collision_manifold(&second_square).unwrap();
+        assert_nearly_eq!(0.0, manifold.depth);
+    }
+
+    #[test]
+    fn collision_manifold_points_towards_other_polygon() {
+        let first_square = square_at(0.0, 0.0);
+        let second_square = square_at(8.0, 1.0);
+
+        let manifold = first_square.collision_manifold(&second_square).unwrap();
+        assert_nearly_eq!(2.0, manifold.depth);
+        assert_nearly_eq!(1.0, manifold.normal.x);
+        assert_nearly_eq!(0.0, manifold.normal.y);
+
+        let manifold = second_square.collision_manifold(&first_square).unwrap();
+        assert_nearly_eq!(2.0, manifold.depth);
+        assert_nearly_eq!(-1.0, manifold.normal.x);
+        assert_nearly_eq!(0.0, manifold.normal.y);
+    }
+
+    #[test]
+    fn collision_manifold_picks_axis_of_least_penetration() {
+        let first_square = square_at(0.0, 0.0);
+        let second_square = square_at(3.0, -9.0);
+
+        let manifold = first_square.collision_manifold(&second_square).unwrap();
+        assert_nearly_eq!(1.0, manifold.depth);
+        assert_nearly_eq!(0.0, manifold.normal.x);
+        assert_nearly_eq!(-1.0, manifold.normal.y);
+    }
+
+    #[test]
+    fn collision_manifold_separates_contained_polygon() {
+        let bigger_polygon = square_at(0.0, 0.0);
+        let smaller_polygon = Polygon::try_new(vec![
+            Point { x: 6.0, y: 4.0 },
+            Point { x: 8.0, y: 4.0 },
+            Point { x: 8.0, y: 6.0 },
+        ])
+        .unwrap();
+
+        let manifold = bigger_polygon.collision_manifold(&smaller_polygon).unwrap();
+        let separated_polygon =
+            smaller_polygon.translate(manifold.minimum_translation_vector().into());
+
+        assert_nearly_eq!(4.0, manifold.depth);
+        assert!(
+            !bigger_polygon.intersects(&separated_polygon.translate(Point { x: 0.001, y: 0.0 }))
+        );
+    }
+
+    #[test]
+    fn ray_hits_polygon() {
+        let triangle = Polygon::try_new(vec![
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 20.0, y: 10.0 },
+            Point { x: 10.0, y: 10.0 },
+        ])
+        .unwrap();
+        let ray = Ray::try_new((20.0, 5.0), Vector { x: -1.0, y: 0.0 }).unwrap();
+
+        let hit = triangle.cast_ray(&ray, None).unwrap();
+
+        assert_nearly_eq!(5.0, hit.time_of_impact);
+        assert_nearly_eq!(15.0, hit.point.x);
+        assert_nearly_eq!(5.0, hit.point.y);
+        assert_nearly_eq!(0.5_f64.sqrt(), hit.normal.x);
+        assert_nearly_eq!(-(0.5_f64.sqrt()), hit.normal.y);
+    }
+
+    #[test]
+    fn ray_hits_polygon_created_from_aabb() {
+        let aabb = Aabb::try_new((10.0, 0.0), (20.0, 10.0)).unwrap();
+        let polygon = Polygon::from(aabb);
+        let ray = Ray::try_new((15.0, -10.0), Vector { x: 0.0, y: 2.0 }).unwrap();
+
+        assert_eq!(aabb.cast_ray(&ray, None), polygon.cast_ray(&ray, None));
+    }
+
+    #[test]
+    fn ray_misses_polygon_when_passing_by() {
+        let ray = Ray::try_new((-20.0, -10.1), Vector { x: 1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, polygon().cast_ray(&ray, None));
+    }
+
+    #[test]
+    fn ray_misses_polygon_when_running_along_outside_of_edge() {
+        let ray = Ray::try_new((-20.0, 20.0), Vector { x: 1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, polygon().cast_ray(&ray, None));
+    }
+
+    #[test]
+    fn ray_misses_polygon_when_too_short() {
+        let ray = Ray::try_new((-20.0, 0.0), Vector { x: 1.0, y: 0.0 }).unwrap();
+        assert_eq!(None, polygon().cast_ray(&ray, Some(9.0)));
+        assert!(polygon().cast_ray(&ray, Some(10.0)).is_some());
+    }
+
+    #[test]
+    fn ray_starting_inside_polygon_hits_immediately() {
+        let ray = Ray::try_new((0.0, 0.0), Vector { x: 1.0, y: 0.0 }).unwrap();
+
+        let hit = polygon().cast_ray(&ray, None).unwrap();
+
+        assert_eq!(0.0, hit.time_of_impact);
+        assert_eq!(Point::default(), hit.point);
+    }
 }
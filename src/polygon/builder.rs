@@ -1,6 +1,6 @@
 //! Builder pattern for a convex [`Polygon`]
 
-use super::Polygon;
+use super::{Polygon, PolygonError};
 use crate::Point;
 
 /// [`Polygon`] factory, which can be used in order to configure
@@ -37,9 +37,10 @@ impl PolygonBuilder {
     /// Finishes building the [`Polygon`] with all
     /// vertices that have been configured up to this point
     /// # Errors
-    /// This method will return an error if the number of configured
+    /// This method will return a [`PolygonError`] if the number of configured
     /// vertices is less than three, as the resulting [`Polygon`]
-    /// would not be two-dimensional.
+    /// would not be two-dimensional, or if the vertices are otherwise invalid.
+    /// See [`Polygon::try_new`] for details.
     /// # Examples
     /// ```
     /// use myelin_geometry::PolygonBuilder;
@@ -54,7 +55,9 @@ impl PolygonBuilder {
     /// ```
     ///
     /// [`Polygon`]: ../object/struct.Polygon.html
-    pub fn build(self) -> Result<Polygon, ()> {
+    /// [`PolygonError`]: ./enum.PolygonError.html
+    /// [`Polygon::try_new`]: ./struct.Polygon.html#method.try_new
+    pub fn build(self) -> Result<Polygon, PolygonError> {
         Polygon::try_new(self.vertices)
     }
 }
@@ -87,18 +90,24 @@ mod tests {
 
     #[test]
     fn test_polygon_builder_errors_for_no_vertices() {
-        assert_eq!(Err(()), PolygonBuilder::default().build());
+        assert_eq!(
+            Err(PolygonError::TooFewVertices { count: 0 }),
+            PolygonBuilder::default().build()
+        );
     }
 
     #[test]
     fn test_polygon_builder_errors_for_one_vertex() {
-        assert_eq!(Err(()), PolygonBuilder::default().vertex(1.0, 1.0).build());
+        assert_eq!(
+            Err(PolygonError::TooFewVertices { count: 1 }),
+            PolygonBuilder::default().vertex(1.0, 1.0).build()
+        );
     }
 
     #[test]
     fn test_polygon_builder_panicks_for_two_vertices() {
         assert_eq!(
-            Err(()),
+            Err(PolygonError::TooFewVertices { count: 2 }),
             PolygonBuilder::default()
                 .vertex(0.0, 0.0)
                 .vertex(1.0, 1.0)
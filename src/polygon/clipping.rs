@@ -0,0 +1,238 @@
+//! Clipping of convex [`Polygon`]s against each other
+
+use super::Polygon;
+use crate::{Aabb, Point, Vector};
+
+/// Relative tolerance under which clipped vertices are considered to coincide
+/// or to lie on a straight line
+const CLIPPING_TOLERANCE: f64 = 0.000_000_001;
+
+impl Polygon {
+    /// Calculates the region in which this polygon and `other` overlap
+    /// by clipping this polygon against every edge of `other`
+    /// ([Sutherland–Hodgman algorithm]).
+    ///
+    /// Returns `None` if the polygons don't overlap or only touch,
+    /// i.e. if the overlap doesn't cover any area.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::PolygonBuilder;
+    ///
+    /// let first = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(10.0, 0.0)
+    ///     .vertex(10.0, 10.0)
+    ///     .vertex(0.0, 10.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let second = first.translate((5.0, 5.0).into());
+    ///
+    /// let overlap = first.intersection(&second).expect("Polygons don't overlap");
+    /// assert_eq!(4, overlap.vertices().len());
+    /// ```
+    ///
+    /// [Sutherland–Hodgman algorithm]: https://en.wikipedia.org/wiki/Sutherland%E2%80%93Hodgman_algorithm
+    pub fn intersection(&self, other: &Polygon) -> Option<Polygon> {
+        let clipped_vertices = other.counter_clockwise_segments().fold(
+            self.counter_clockwise_vertices(),
+            |vertices, segment| {
+                let outward_normal = segment.direction().normal().negative().unit();
+                clip_to_half_plane(&vertices, segment.start, outward_normal)
+            },
+        );
+
+        let scale = self.aabb_diagonal().max(other.aabb_diagonal());
+        polygon_from_clipped_vertices(clipped_vertices, CLIPPING_TOLERANCE * scale)
+    }
+
+    /// Calculates the part of this polygon that lies within `aabb`.
+    ///
+    /// Returns `None` if the polygon lies outside of `aabb` or only touches it.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Aabb, PolygonBuilder};
+    ///
+    /// let triangle = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(10.0, 0.0)
+    ///     .vertex(0.0, 10.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let aabb = Aabb::try_new((5.0, -5.0), (15.0, 5.0)).unwrap();
+    ///
+    /// let clipped = triangle.clip_to_aabb(aabb).expect("Triangle is outside of aabb");
+    /// assert_eq!(3, clipped.vertices().len());
+    /// ```
+    pub fn clip_to_aabb(&self, aabb: Aabb) -> Option<Polygon> {
+        self.intersection(&Polygon::from(aabb))
+    }
+
+    fn aabb_diagonal(&self) -> f64 {
+        let aabb = self.aabb();
+        Vector::from(aabb.lower_right - aabb.upper_left).magnitude()
+    }
+}
+
+/// Cuts away the part of a convex ring of vertices that lies on the side
+/// of the line through `point_on_boundary` that `outward_normal` points to
+pub(crate) fn clip_to_half_plane(
+    vertices: &[Point],
+    point_on_boundary: Point,
+    outward_normal: Vector,
+) -> Vec<Point> {
+    let signed_distance =
+        |point: Point| Vector::from(point - point_on_boundary).dot_product(outward_normal);
+
+    let mut clipped_vertices = Vec::with_capacity(vertices.len() + 1);
+    for (index, &current) in vertices.iter().enumerate() {
+        let next = vertices[(index + 1) % vertices.len()];
+        let current_distance = signed_distance(current);
+        let next_distance = signed_distance(next);
+
+        if current_distance <= 0.0 {
+            clipped_vertices.push(current);
+        }
+        if (current_distance < 0.0 && next_distance > 0.0)
+            || (current_distance > 0.0 && next_distance < 0.0)
+        {
+            let fraction = current_distance / (current_distance - next_distance);
+            clipped_vertices.push(Point {
+                x: current.x + (next.x - current.x) * fraction,
+                y: current.y + (next.y - current.y) * fraction,
+            });
+        }
+    }
+    clipped_vertices
+}
+
+/// Turns the result of clipping into a [`Polygon`], after removing redundant vertices.
+/// Returns `None` if the vertices don't enclose any area.
+pub(crate) fn polygon_from_clipped_vertices(
+    mut vertices: Vec<Point>,
+    tolerance: f64,
+) -> Option<Polygon> {
+    remove_redundant_vertices(&mut vertices, tolerance);
+    Polygon::try_new(vertices).ok()
+}
+
+/// Removes vertices of a closed ring that coincide with their predecessor
+/// or lie on a straight line between their neighbours
+pub(crate) fn remove_redundant_vertices(vertices: &mut Vec<Point>, tolerance: f64) {
+    let mut position = 0;
+    while vertices.len() >= 3 && position < vertices.len() {
+        let previous = vertices[(position + vertices.len() - 1) % vertices.len()];
+        let current = vertices[position];
+        let next = vertices[(position + 1) % vertices.len()];
+
+        let incoming = Vector::from(current - previous);
+        let outgoing = Vector::from(next - current);
+        let is_duplicate = incoming.magnitude() <= tolerance;
+        let is_straight = incoming.cross_product(outgoing).abs()
+            <= tolerance * incoming.magnitude().max(outgoing.magnitude());
+        if is_duplicate || is_straight {
+            vertices.remove(position);
+            position = position.saturating_sub(1);
+        } else {
+            position += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PolygonBuilder, SimplePolygon};
+    use nearly_eq::assert_nearly_eq;
+
+    fn area(polygon: &Polygon) -> f64 {
+        SimplePolygon::from(polygon.clone()).area()
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        PolygonBuilder::default()
+            .vertex(x, y)
+            .vertex(x + size, y)
+            .vertex(x + size, y + size)
+            .vertex(x, y + size)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn intersection_of_overlapping_squares_is_square() {
+        let overlap = square(0.0, 0.0, 10.0)
+            .intersection(&square(5.0, 5.0, 10.0))
+            .unwrap();
+
+        assert_nearly_eq!(25.0, area(&overlap));
+        assert!(overlap.contains_point(Point { x: 7.5, y: 7.5 }));
+    }
+
+    #[test]
+    fn intersection_with_contained_polygon_is_contained_polygon() {
+        let inner = square(2.0, 2.0, 2.0);
+
+        let overlap = square(0.0, 0.0, 10.0).intersection(&inner).unwrap();
+
+        assert_nearly_eq!(4.0, area(&overlap));
+        assert_eq!(4, overlap.vertices().len());
+    }
+
+    #[test]
+    fn intersection_of_differently_ordered_polygons_is_symmetric() {
+        let triangle = PolygonBuilder::default()
+            .vertex(5.0, -5.0)
+            .vertex(5.0, 15.0)
+            .vertex(15.0, 5.0)
+            .build()
+            .unwrap();
+        let square = square(0.0, 0.0, 10.0);
+
+        let first_overlap = square.intersection(&triangle).unwrap();
+        let second_overlap = triangle.intersection(&square).unwrap();
+
+        assert_nearly_eq!(area(&first_overlap), area(&second_overlap));
+        assert_nearly_eq!(50.0, area(&first_overlap));
+    }
+
+    #[test]
+    fn intersection_of_touching_polygons_is_none() {
+        assert_eq!(
+            None,
+            square(0.0, 0.0, 10.0).intersection(&square(10.0, 0.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn intersection_of_distant_polygons_is_none() {
+        assert_eq!(
+            None,
+            square(0.0, 0.0, 10.0).intersection(&square(20.0, 20.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn clip_to_aabb_cuts_off_protruding_part() {
+        let aabb = Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap();
+
+        let clipped = square(-5.0, 5.0, 10.0).clip_to_aabb(aabb).unwrap();
+
+        assert_nearly_eq!(25.0, area(&clipped));
+    }
+
+    #[test]
+    fn clip_to_half_plane_keeps_inner_side() {
+        let vertices = square(0.0, 0.0, 10.0).counter_clockwise_vertices();
+
+        let clipped = clip_to_half_plane(
+            &vertices,
+            Point { x: 4.0, y: 0.0 },
+            Vector { x: 1.0, y: 0.0 },
+        );
+
+        let polygon = polygon_from_clipped_vertices(clipped, 0.000_001).unwrap();
+        assert_nearly_eq!(40.0, area(&polygon));
+    }
+}
@@ -0,0 +1,369 @@
+//! Contact point generation between two convex [`Polygon`]s
+
+use super::Polygon;
+use crate::{Contact, ContactId, Feature, Point, Vector};
+
+/// Bias towards choosing the first polygon's edge as reference edge,
+/// so that the chosen features don't flip-flop between frames
+const REFERENCE_EDGE_TOLERANCE: f64 = 0.000_5;
+
+/// Maximal separation of a clipped point from the reference edge
+/// for it to still be considered touching
+const CONTACT_TOLERANCE: f64 = 0.000_001;
+
+impl Polygon {
+    /// Calculates the points at which this polygon and `other` touch.
+    ///
+    /// Two convex polygons touch in at most two points. The edge of the polygon
+    /// that is penetrated the least serves as reference edge, against which the
+    /// most anti-parallel edge of the other polygon (the incident edge) is clipped.
+    ///
+    /// Returns an empty vector if the polygons don't intersect.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, PolygonBuilder};
+    ///
+    /// let ground = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(10.0, 0.0)
+    ///     .vertex(10.0, 2.0)
+    ///     .vertex(0.0, 2.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let box_on_ground = PolygonBuilder::default()
+    ///     .vertex(4.0, 1.5)
+    ///     .vertex(6.0, 1.5)
+    ///     .vertex(6.0, 3.5)
+    ///     .vertex(4.0, 3.5)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let contacts = ground.contact_points(&box_on_ground);
+    /// let points: Vec<_> = contacts.iter().map(|contact| contact.point).collect();
+    /// assert_eq!(
+    ///     vec![Point { x: 4.0, y: 1.5 }, Point { x: 6.0, y: 1.5 }],
+    ///     points
+    /// );
+    /// ```
+    pub fn contact_points(&self, other: &Polygon) -> Vec<Contact> {
+        let own_edges = OrderedEdges::new(self);
+        let other_edges = OrderedEdges::new(other);
+
+        let (own_edge, own_separation) = own_edges.max_separation(&other_edges);
+        if own_separation > 0.0 {
+            return Vec::new();
+        }
+        let (other_edge, other_separation) = other_edges.max_separation(&own_edges);
+        if other_separation > 0.0 {
+            return Vec::new();
+        }
+
+        let (reference, reference_edge, incident, flipped) =
+            if other_separation > own_separation + REFERENCE_EDGE_TOLERANCE {
+                (&other_edges, other_edge, &own_edges, true)
+            } else {
+                (&own_edges, own_edge, &other_edges, false)
+            };
+
+        let reference_normal = reference.outward_normal(reference_edge);
+        let incident_edge = incident.most_anti_parallel_edge(reference_normal);
+
+        let (reference_start, reference_end) = reference.edge_vertices(reference_edge);
+        let (incident_start, incident_end) = incident.edge_vertices(incident_edge);
+        let reference_start_point = reference.point(reference_start);
+        let reference_end_point = reference.point(reference_end);
+        let tangent = Vector::from(reference_end_point - reference_start_point).unit();
+
+        let incident_points = [
+            ClipPoint {
+                point: incident.point(incident_start),
+                reference_feature: Feature::Edge(reference_start),
+                incident_feature: Feature::Vertex(incident_start),
+            },
+            ClipPoint {
+                point: incident.point(incident_end),
+                reference_feature: Feature::Edge(reference_start),
+                incident_feature: Feature::Vertex(incident_end),
+            },
+        ];
+
+        // Clip the incident edge against the side planes of the reference edge
+        let clipped_points = clip(
+            &incident_points,
+            tangent.negative(),
+            -tangent.dot_product(reference_start_point.into()),
+            Feature::Vertex(reference_start),
+            Feature::Edge(incident_start),
+        );
+        let clipped_points = clip(
+            &clipped_points,
+            tangent,
+            tangent.dot_product(reference_end_point.into()),
+            Feature::Vertex(reference_end),
+            Feature::Edge(incident_start),
+        );
+
+        // Keep only the points that lie behind the reference edge
+        let reference_offset = reference_normal.dot_product(reference_start_point.into());
+        clipped_points
+            .into_iter()
+            .filter_map(|clip_point| {
+                let separation =
+                    reference_normal.dot_product(clip_point.point.into()) - reference_offset;
+                if separation > CONTACT_TOLERANCE {
+                    return None;
+                }
+
+                let id = if flipped {
+                    ContactId {
+                        first: clip_point.incident_feature,
+                        second: clip_point.reference_feature,
+                    }
+                } else {
+                    ContactId {
+                        first: clip_point.reference_feature,
+                        second: clip_point.incident_feature,
+                    }
+                };
+
+                Some(Contact {
+                    point: clip_point.point,
+                    depth: (-separation).max(0.0),
+                    id,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The edges of a polygon in counter clockwise order
+#[derive(Debug)]
+struct OrderedEdges<'a> {
+    polygon: &'a Polygon,
+    vertex_indices: Vec<usize>,
+}
+
+impl<'a> OrderedEdges<'a> {
+    fn new(polygon: &'a Polygon) -> Self {
+        Self {
+            polygon,
+            vertex_indices: polygon.counter_clockwise_vertex_indices(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.vertex_indices.len()
+    }
+
+    fn point(&self, vertex_index: usize) -> Point {
+        self.polygon.vertices[vertex_index]
+    }
+
+    /// Returns the indices of the start and end vertex of the `edge`th edge
+    fn edge_vertices(&self, edge: usize) -> (usize, usize) {
+        (
+            self.vertex_indices[edge],
+            self.vertex_indices[(edge + 1) % self.len()],
+        )
+    }
+
+    fn outward_normal(&self, edge: usize) -> Vector {
+        let (start, end) = self.edge_vertices(edge);
+        let edge_vector = Vector::from(self.point(end) - self.point(start));
+        // The vertices are ordered counter clockwise, so the
+        // left-hand normal points into the polygon
+        edge_vector.normal().negative().unit()
+    }
+
+    /// Returns the edge whose normal separates `other` the most from this polygon,
+    /// together with the separation. Negative separations indicate penetration.
+    fn max_separation(&self, other: &OrderedEdges<'_>) -> (usize, f64) {
+        (0..self.len())
+            .map(|edge| {
+                let normal = self.outward_normal(edge);
+                let (start, _) = self.edge_vertices(edge);
+                let offset = normal.dot_product(self.point(start).into());
+                let separation = other
+                    .polygon
+                    .vertices
+                    .iter()
+                    .map(|&vertex| normal.dot_product(vertex.into()) - offset)
+                    .fold(f64::INFINITY, f64::min);
+                (edge, separation)
+            })
+            .fold((0, f64::NEG_INFINITY), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            })
+    }
+
+    fn most_anti_parallel_edge(&self, normal: Vector) -> usize {
+        (0..self.len())
+            .map(|edge| (edge, self.outward_normal(edge).dot_product(normal)))
+            .fold((0, f64::INFINITY), |best, candidate| {
+                if candidate.1 < best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .0
+    }
+}
+
+/// A point on the incident edge, together with the features it originates from
+#[derive(Debug, Copy, Clone)]
+struct ClipPoint {
+    point: Point,
+    reference_feature: Feature,
+    incident_feature: Feature,
+}
+
+/// Clips the segment described by `points` against the half-plane
+/// `normal · p <= offset`. Points created by the clipping are attributed
+/// to the `reference_feature` describing the plane and the clipped `incident_feature`.
+fn clip(
+    points: &[ClipPoint],
+    normal: Vector,
+    offset: f64,
+    reference_feature: Feature,
+    incident_feature: Feature,
+) -> Vec<ClipPoint> {
+    let distances: Vec<_> = points
+        .iter()
+        .map(|clip_point| normal.dot_product(clip_point.point.into()) - offset)
+        .collect();
+
+    let mut clipped_points: Vec<_> = points
+        .iter()
+        .zip(&distances)
+        .filter(|(_, &distance)| distance <= 0.0)
+        .map(|(&clip_point, _)| clip_point)
+        .collect();
+
+    if let [first, second] = points {
+        let (first_distance, second_distance) = (distances[0], distances[1]);
+        if first_distance * second_distance < 0.0 {
+            let interpolation = first_distance / (first_distance - second_distance);
+            let direction = Vector::from(second.point - first.point);
+            let intersection = ClipPoint {
+                point: first.point + Point::from(direction * interpolation),
+                reference_feature,
+                incident_feature,
+            };
+            if first_distance > 0.0 {
+                clipped_points.insert(0, intersection);
+            } else {
+                clipped_points.push(intersection);
+            }
+        }
+    }
+
+    clipped_points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PolygonBuilder;
+    use nearly_eq::assert_nearly_eq;
+
+    fn rectangle(upper_left: (f64, f64), lower_right: (f64, f64)) -> Polygon {
+        PolygonBuilder::default()
+            .vertex(upper_left.0, upper_left.1)
+            .vertex(lower_right.0, upper_left.1)
+            .vertex(lower_right.0, lower_right.1)
+            .vertex(upper_left.0, lower_right.1)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn no_contacts_when_apart() {
+        let first = rectangle((0.0, 0.0), (10.0, 10.0));
+        let second = rectangle((20.0, 0.0), (30.0, 10.0));
+        assert!(first.contact_points(&second).is_empty());
+        assert!(second.contact_points(&first).is_empty());
+    }
+
+    #[test]
+    fn two_contacts_for_resting_box() {
+        let ground = rectangle((0.0, 0.0), (10.0, 2.0));
+        let resting_box = rectangle((4.0, 1.5), (6.0, 3.5));
+
+        let contacts = ground.contact_points(&resting_box);
+
+        assert_eq!(2, contacts.len());
+        assert_eq!(Point { x: 4.0, y: 1.5 }, contacts[0].point);
+        assert_eq!(Point { x: 6.0, y: 1.5 }, contacts[1].point);
+        for contact in &contacts {
+            assert_nearly_eq!(0.5, contact.depth);
+        }
+        assert_eq!(
+            ContactId {
+                first: Feature::Edge(2),
+                second: Feature::Vertex(0),
+            },
+            contacts[0].id
+        );
+    }
+
+    #[test]
+    fn edges_are_clipped_to_reference_edge() {
+        let ground = rectangle((0.0, 0.0), (10.0, 2.0));
+        let overhanging_box = rectangle((8.0, 1.0), (14.0, 5.0));
+
+        let contacts = overhanging_box.contact_points(&ground);
+
+        assert_eq!(2, contacts.len());
+        let clipped_contact = contacts
+            .iter()
+            .find(|contact| contact.id.second == Feature::Edge(2))
+            .expect("No contact was created by clipping");
+        assert_nearly_eq!(8.0, clipped_contact.point.x);
+        assert_nearly_eq!(2.0, clipped_contact.point.y);
+        assert_eq!(Feature::Vertex(0), clipped_contact.id.first);
+    }
+
+    #[test]
+    fn single_contact_for_vertex_hitting_edge() {
+        let ground = rectangle((0.0, 0.0), (10.0, 2.0));
+        let triangle = PolygonBuilder::default()
+            .vertex(5.0, 1.0)
+            .vertex(7.0, 4.0)
+            .vertex(3.0, 4.0)
+            .build()
+            .unwrap();
+
+        let contacts = ground.contact_points(&triangle);
+
+        assert_eq!(1, contacts.len());
+        assert_eq!(Point { x: 5.0, y: 1.0 }, contacts[0].point);
+        assert_nearly_eq!(1.0, contacts[0].depth);
+        assert_eq!(Feature::Vertex(0), contacts[0].id.second);
+    }
+
+    #[test]
+    fn contact_ids_are_stable_when_moving_slightly() {
+        let ground = rectangle((0.0, 0.0), (10.0, 2.0));
+        let resting_box = rectangle((4.0, 1.5), (6.0, 3.5));
+        let moved_box = resting_box.translate(Point { x: 0.1, y: 0.05 });
+
+        let ids: Vec<_> = ground
+            .contact_points(&resting_box)
+            .iter()
+            .map(|contact| contact.id)
+            .collect();
+        let moved_ids: Vec<_> = ground
+            .contact_points(&moved_box)
+            .iter()
+            .map(|contact| contact.id)
+            .collect();
+
+        assert_eq!(ids, moved_ids);
+    }
+}
@@ -0,0 +1,330 @@
+//! Minkowski sums and differences of convex [`Polygon`]s
+
+use super::Polygon;
+use crate::{ConvexHull, ConvexHullError, Point, Vector};
+use itertools::Itertools;
+use std::cmp::Ordering;
+
+/// Relative tolerance under which two edges are considered parallel
+const PARALLEL_TOLERANCE: f64 = 0.000_000_001;
+
+impl Polygon {
+    /// Calculates the [Minkowski sum] of this polygon and `other`,
+    /// i.e. the polygon covered by `other` when its origin is moved over every point of this polygon.
+    /// This inflates an obstacle by the shape of an agent.
+    ///
+    /// Both polygons' edges are merged by their direction,
+    /// which takes O(n + m) time for polygons with n and m vertices.
+    ///
+    /// # Errors
+    /// Returns a [`ConvexHullError`] if the sum can't be represented as a [`Polygon`]:
+    /// [`ConvexHullError::NoPoints`] if either polygon has no vertices, like the default polygon,
+    /// [`ConvexHullError::NonFinitePoint`] if adding the vertices overflows,
+    /// and [`ConvexHullError::Degenerate`] if rounding leaves the sum without an area,
+    /// e.g. when a tiny polygon is added to one that is far away from the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, PolygonBuilder};
+    ///
+    /// let obstacle = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(10.0, 0.0)
+    ///     .vertex(10.0, 10.0)
+    ///     .vertex(0.0, 10.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let agent = PolygonBuilder::default()
+    ///     .vertex(-1.0, -1.0)
+    ///     .vertex(1.0, -1.0)
+    ///     .vertex(1.0, 1.0)
+    ///     .vertex(-1.0, 1.0)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let inflated_obstacle = obstacle.minkowski_sum(&agent).unwrap();
+    /// assert_eq!(
+    ///     &[
+    ///         Point { x: -1.0, y: -1.0 },
+    ///         Point { x: 11.0, y: -1.0 },
+    ///         Point { x: 11.0, y: 11.0 },
+    ///         Point { x: -1.0, y: 11.0 },
+    ///     ],
+    ///     inflated_obstacle.vertices()
+    /// );
+    /// ```
+    ///
+    /// [Minkowski sum]: https://en.wikipedia.org/wiki/Minkowski_addition
+    /// [`ConvexHullError`]: ./enum.ConvexHullError.html
+    /// [`Polygon`]: ./struct.Polygon.html
+    /// [`ConvexHullError::NoPoints`]: ./enum.ConvexHullError.html#variant.NoPoints
+    /// [`ConvexHullError::NonFinitePoint`]: ./enum.ConvexHullError.html#variant.NonFinitePoint
+    /// [`ConvexHullError::Degenerate`]: ./enum.ConvexHullError.html#variant.Degenerate
+    pub fn minkowski_sum(&self, other: &Polygon) -> Result<Polygon, ConvexHullError> {
+        merge_edges(
+            &counter_clockwise_vertices_from_leftmost(self),
+            &counter_clockwise_vertices_from_leftmost(other),
+        )
+    }
+
+    /// Calculates the Minkowski difference of this polygon and `other`,
+    /// i.e. the Minkowski sum of this polygon and `other` reflected through the origin.
+    /// The result contains the origin exactly when the two polygons intersect,
+    /// and its distance to the origin is the distance between the polygons.
+    ///
+    /// # Errors
+    /// Returns a [`ConvexHullError`] under the same conditions as [`Polygon::minkowski_sum`].
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, PolygonBuilder};
+    ///
+    /// let square = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(10.0, 0.0)
+    ///     .vertex(10.0, 10.0)
+    ///     .vertex(0.0, 10.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let overlapping_square = square.translate((5.0, 5.0).into());
+    /// let distant_square = square.translate((20.0, 0.0).into());
+    ///
+    /// assert!(square
+    ///     .minkowski_difference(&overlapping_square)
+    ///     .unwrap()
+    ///     .contains_point(Point::default()));
+    /// assert!(!square
+    ///     .minkowski_difference(&distant_square)
+    ///     .unwrap()
+    ///     .contains_point(Point::default()));
+    /// ```
+    ///
+    /// [`ConvexHullError`]: ./enum.ConvexHullError.html
+    /// [`Polygon::minkowski_sum`]: ./struct.Polygon.html#method.minkowski_sum
+    pub fn minkowski_difference(&self, other: &Polygon) -> Result<Polygon, ConvexHullError> {
+        // Reflecting through the origin is a rotation by 180°,
+        // so the reflected polygon stays convex
+        let reflected_other = Polygon {
+            vertices: other
+                .vertices
+                .iter()
+                .map(|vertex| Point {
+                    x: -vertex.x,
+                    y: -vertex.y,
+                })
+                .collect(),
+        };
+        self.minkowski_sum(&reflected_other)
+    }
+}
+
+/// Returns the vertices of `polygon` in counter clockwise order,
+/// starting at the leftmost one (the lowest one, if several share the smallest x coordinate)
+fn counter_clockwise_vertices_from_leftmost(polygon: &Polygon) -> Vec<Point> {
+    let mut vertices = polygon.counter_clockwise_vertices();
+    if let Some(leftmost) = vertices
+        .iter()
+        .position_min_by(|first, second| first.partial_cmp(second).unwrap_or(Ordering::Equal))
+    {
+        vertices.rotate_left(leftmost);
+    }
+    vertices
+}
+
+/// Walks along the edges of two counter clockwise vertex rings at the same time,
+/// always taking the edge that turns the least. Both rings need to start at their
+/// leftmost vertex, so that their sum starts at the sum of these vertices.
+fn merge_edges(first: &[Point], second: &[Point]) -> Result<Polygon, ConvexHullError> {
+    if first.is_empty() || second.is_empty() {
+        return Err(ConvexHullError::NoPoints);
+    }
+
+    let edge =
+        |vertices: &[Point], index: usize| vertices[(index + 1) % vertices.len()] - vertices[index];
+
+    let mut vertices = Vec::with_capacity(first.len() + second.len());
+    let mut current_vertex = first[0] + second[0];
+    let (mut first_index, mut second_index) = (0, 0);
+    while first_index < first.len() || second_index < second.len() {
+        vertices.push(current_vertex);
+
+        let order = if first_index == first.len() {
+            Ordering::Greater
+        } else if second_index == second.len() {
+            Ordering::Less
+        } else {
+            compare_directions(edge(first, first_index), edge(second, second_index))
+        };
+
+        if order != Ordering::Greater {
+            current_vertex = current_vertex + edge(first, first_index);
+            first_index += 1;
+        }
+        if order != Ordering::Less {
+            current_vertex = current_vertex + edge(second, second_index);
+            second_index += 1;
+        }
+    }
+
+    // Rounding can leave vertices slightly inside of the polygon,
+    // which the convex hull removes again
+    ConvexHull::try_new(vertices).and_then(ConvexHull::into_polygon)
+}
+
+/// Orders two edges by how far they turn counter clockwise.
+/// Returns [`Ordering::Equal`] if they are parallel.
+///
+/// [`Ordering::Equal`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html#variant.Equal
+fn compare_directions(first: Point, second: Point) -> Ordering {
+    let first = Vector::from(first);
+    let second = Vector::from(second);
+    let cross_product = first.cross_product(second);
+    if cross_product.abs() <= PARALLEL_TOLERANCE * first.magnitude() * second.magnitude() {
+        Ordering::Equal
+    } else if cross_product > 0.0 {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PolygonBuilder, SimplePolygon};
+    use nearly_eq::assert_nearly_eq;
+    use std::f64::consts::SQRT_2;
+
+    fn area(polygon: &Polygon) -> f64 {
+        SimplePolygon::from(polygon.clone()).area()
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        PolygonBuilder::default()
+            .vertex(x, y)
+            .vertex(x + size, y)
+            .vertex(x + size, y + size)
+            .vertex(x, y + size)
+            .build()
+            .unwrap()
+    }
+
+    fn triangle() -> Polygon {
+        PolygonBuilder::default()
+            .vertex(0.0, 0.0)
+            .vertex(1.0, 0.0)
+            .vertex(0.0, 1.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn minkowski_sum_merges_parallel_edges() {
+        let sum = triangle().minkowski_sum(&square(0.0, 0.0, 1.0)).unwrap();
+
+        assert_eq!(
+            &[
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 2.0, y: 0.0 },
+                Point { x: 2.0, y: 1.0 },
+                Point { x: 1.0, y: 2.0 },
+                Point { x: 0.0, y: 2.0 },
+            ],
+            sum.vertices()
+        );
+    }
+
+    #[test]
+    fn minkowski_sum_is_commutative() {
+        let first = triangle().minkowski_sum(&square(3.0, -2.0, 2.0));
+        let second = square(3.0, -2.0, 2.0).minkowski_sum(&triangle());
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn minkowski_sum_ignores_vertex_order() {
+        let clockwise_triangle = PolygonBuilder::default()
+            .vertex(0.0, 1.0)
+            .vertex(1.0, 0.0)
+            .vertex(0.0, 0.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            triangle().minkowski_sum(&square(0.0, 0.0, 1.0)),
+            clockwise_triangle.minkowski_sum(&square(0.0, 0.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn minkowski_sum_of_rotated_polygons_has_all_edges() {
+        let rotated_square = square(-1.0, -1.0, 2.0).rotate_around_point(
+            crate::Radians::try_new(std::f64::consts::FRAC_PI_4).unwrap(),
+            Point::default(),
+        );
+
+        let sum = square(0.0, 0.0, 4.0)
+            .minkowski_sum(&rotated_square)
+            .unwrap();
+
+        assert_eq!(8, sum.vertices().len());
+        // The square grows by the rotated square's half diagonal on every side,
+        // with its corners cut off by the rotated square's edges
+        let grown_side = 4.0 + 2.0 * SQRT_2;
+        assert_nearly_eq!(grown_side * grown_side - 4.0, area(&sum));
+    }
+
+    #[test]
+    fn minkowski_difference_contains_origin_when_touching() {
+        let difference = square(0.0, 0.0, 10.0)
+            .minkowski_difference(&square(10.0, 0.0, 10.0))
+            .unwrap();
+
+        assert!(difference.contains_point(Point::default()));
+        assert_nearly_eq!(400.0, area(&difference));
+    }
+
+    #[test]
+    fn minkowski_difference_of_distant_polygons_excludes_origin() {
+        let difference = triangle()
+            .minkowski_difference(&square(5.0, 5.0, 1.0))
+            .unwrap();
+
+        assert!(!difference.contains_point(Point::default()));
+    }
+
+    #[test]
+    fn minkowski_sum_errors_when_vertices_overflow() {
+        let huge_square = square(1e308, 1e308, 1e307);
+
+        assert!(matches!(
+            huge_square.minkowski_sum(&huge_square),
+            Err(ConvexHullError::NonFinitePoint { .. })
+        ));
+    }
+
+    #[test]
+    fn minkowski_sum_errors_for_empty_polygon() {
+        assert_eq!(
+            Err(ConvexHullError::NoPoints),
+            Polygon::default().minkowski_sum(&triangle())
+        );
+        assert_eq!(
+            Err(ConvexHullError::NoPoints),
+            triangle().minkowski_sum(&Polygon::default())
+        );
+    }
+
+    #[test]
+    fn minkowski_difference_errors_for_empty_polygon() {
+        assert_eq!(
+            Err(ConvexHullError::NoPoints),
+            Polygon::default().minkowski_difference(&triangle())
+        );
+        assert_eq!(
+            Err(ConvexHullError::NoPoints),
+            triangle().minkowski_difference(&Polygon::default())
+        );
+    }
+}
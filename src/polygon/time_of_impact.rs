@@ -0,0 +1,353 @@
+//! Continuous collision detection between moving [`Polygon`]s
+
+use super::Polygon;
+use crate::{Point, Proximity, Radians, Support, Vector};
+
+/// Relative tolerance under which moving polygons are considered to touch
+const CONTACT_TOLERANCE: f64 = 0.000_000_001;
+
+/// Upper bound for the number of steps by which the polygons are advanced
+const MAX_ITERATIONS: usize = 256;
+
+/// How a [`Polygon`] moves during a time step
+///
+/// [`Polygon`]: ./struct.Polygon.html
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Motion {
+    /// The distance travelled per unit of time
+    pub linear_velocity: Vector,
+    /// The rotation in radians per unit of time around the polygon's centroid.
+    /// Positive values turn in the direction described in [`Radians`],
+    /// negative values turn the other way.
+    ///
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    pub angular_velocity: f64,
+}
+
+/// The moment at which two moving polygons first touch
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Impact {
+    /// The time that passed since the start of the time step
+    pub time: f64,
+    /// The unit vector pointing from the first polygon towards the second one
+    /// at the time of impact
+    pub normal: Vector,
+}
+
+impl Polygon {
+    /// Calculates when this polygon first touches `other` while both move
+    /// according to their [`Motion`]s for `duration` units of time,
+    /// using conservative advancement: Both polygons are repeatedly moved forward
+    /// by the time they need at least to close the gap between them.
+    /// Unlike checking for intersections at the end of the time step, this
+    /// doesn't let fast polygons tunnel through thin ones.
+    ///
+    /// Returns `None` if the polygons don't touch during the time step.
+    /// Polygons that overlap at the start of the time step have an impact at time zero.
+    ///
+    /// # Panics
+    /// Panics if an angular velocity is `NaN` or infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Intersects, Motion, PolygonBuilder, Vector};
+    ///
+    /// let bullet = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(1.0, 0.0)
+    ///     .vertex(1.0, 1.0)
+    ///     .vertex(0.0, 1.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let wall = PolygonBuilder::default()
+    ///     .vertex(10.0, -5.0)
+    ///     .vertex(10.5, -5.0)
+    ///     .vertex(10.5, 5.0)
+    ///     .vertex(10.0, 5.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let bullet_motion = Motion {
+    ///     linear_velocity: Vector { x: 90.0, y: 0.0 },
+    ///     ..Motion::default()
+    /// };
+    ///
+    /// assert!(!bullet.translate((90.0, 0.0).into()).intersects(&wall));
+    /// let impact = bullet
+    ///     .time_of_impact(bullet_motion, &wall, Motion::default(), 1.0)
+    ///     .expect("Bullet tunnels through wall");
+    /// assert_eq!(0.1, impact.time);
+    /// assert_eq!(Vector { x: 1.0, y: 0.0 }, impact.normal);
+    /// ```
+    ///
+    /// [`Motion`]: ./struct.Motion.html
+    pub fn time_of_impact(
+        &self,
+        own_motion: Motion,
+        other: &Polygon,
+        other_motion: Motion,
+        duration: f64,
+    ) -> Option<Impact> {
+        let own_body = MovingPolygon::new(self, own_motion);
+        let other_body = MovingPolygon::new(other, other_motion);
+        let relative_velocity = other_motion.linear_velocity - own_motion.linear_velocity;
+        // Rotating points can't move faster than the polygon's furthest vertex
+        let rotational_speed = own_body.rotational_speed() + other_body.rotational_speed();
+        let tolerance = CONTACT_TOLERANCE * (own_body.radius + other_body.radius).max(1.0);
+
+        let mut time = 0.0;
+        let mut normal = Vector::default();
+        for _ in 0..MAX_ITERATIONS {
+            let own_polygon = own_body.at(time);
+            let other_polygon = other_body.at(time);
+            let closest_points = match own_polygon.proximity(&other_polygon) {
+                Proximity::Overlapping(manifold) => {
+                    return Some(Impact {
+                        time,
+                        normal: manifold.normal,
+                    })
+                }
+                Proximity::Apart(closest_points) => closest_points,
+            };
+
+            normal = Vector::from(closest_points.second - closest_points.first).unit();
+            if closest_points.distance <= tolerance {
+                return Some(Impact { time, normal });
+            }
+
+            let approaching_speed = rotational_speed - relative_velocity.dot_product(normal);
+            if approaching_speed <= 0.0 {
+                return None;
+            }
+            time += closest_points.distance / approaching_speed;
+            if time > duration {
+                return None;
+            }
+        }
+
+        // The polygons are still approaching each other, so report
+        // the earliest time at which they could touch
+        Some(Impact { time, normal })
+    }
+}
+
+/// A polygon together with what is needed to move it
+#[derive(Debug)]
+struct MovingPolygon<'a> {
+    polygon: &'a Polygon,
+    motion: Motion,
+    centroid: Point,
+    /// The distance from the centroid to the furthest vertex
+    radius: f64,
+}
+
+impl<'a> MovingPolygon<'a> {
+    fn new(polygon: &'a Polygon, motion: Motion) -> Self {
+        let centroid = centroid(&polygon.counter_clockwise_vertices());
+        let radius = polygon
+            .vertices
+            .iter()
+            .map(|&vertex| Vector::from(vertex - centroid).magnitude())
+            .fold(0.0, f64::max);
+        Self {
+            polygon,
+            motion,
+            centroid,
+            radius,
+        }
+    }
+
+    fn rotational_speed(&self) -> f64 {
+        self.motion.angular_velocity.abs() * self.radius
+    }
+
+    /// Returns the polygon after it moved for `time` units of time
+    fn at(&self, time: f64) -> Polygon {
+        let rotation = Radians::try_new_normalized(self.motion.angular_velocity * time)
+            .expect("Angular velocity is NaN or infinite");
+        self.polygon
+            .rotate_around_point(rotation, self.centroid)
+            .translate(Point::from(self.motion.linear_velocity * time))
+    }
+}
+
+/// Calculates the center of mass of a counter clockwise vertex ring
+fn centroid(vertices: &[Point]) -> Point {
+    let mut area = 0.0;
+    let mut weighted_sum = Vector::default();
+    for (index, &start) in vertices.iter().enumerate() {
+        let end = vertices[(index + 1) % vertices.len()];
+        let cross_product = Vector::from(start).cross_product(Vector::from(end));
+        area += cross_product;
+        weighted_sum = weighted_sum + (Vector::from(start) + Vector::from(end)) * cross_product;
+    }
+    Point::from(weighted_sum / (3.0 * area))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Intersects, PolygonBuilder};
+    use nearly_eq::assert_nearly_eq;
+    use std::f64::consts::{FRAC_PI_2, TAU};
+
+    fn rectangle(x: f64, y: f64, width: f64, height: f64) -> Polygon {
+        PolygonBuilder::default()
+            .vertex(x, y)
+            .vertex(x + width, y)
+            .vertex(x + width, y + height)
+            .vertex(x, y + height)
+            .build()
+            .unwrap()
+    }
+
+    fn linear(x: f64, y: f64) -> Motion {
+        Motion {
+            linear_velocity: Vector { x, y },
+            ..Motion::default()
+        }
+    }
+
+    #[test]
+    fn fast_polygon_hits_thin_wall() {
+        let wall = rectangle(50.0, -20.0, 0.1, 40.0);
+
+        let impact = rectangle(0.0, 0.0, 10.0, 10.0)
+            .time_of_impact(linear(100.0, 0.0), &wall, Motion::default(), 1.0)
+            .unwrap();
+
+        assert_nearly_eq!(0.4, impact.time);
+        assert_nearly_eq!(1.0, impact.normal.x);
+        assert_nearly_eq!(0.0, impact.normal.y);
+    }
+
+    #[test]
+    fn polygons_moving_towards_each_other_close_gap_together() {
+        let impact = rectangle(0.0, 0.0, 10.0, 10.0)
+            .time_of_impact(
+                linear(5.0, 5.0),
+                &rectangle(30.0, 30.0, 10.0, 10.0),
+                linear(-5.0, -5.0),
+                10.0,
+            )
+            .unwrap();
+
+        assert_nearly_eq!(2.0, impact.time, 0.000_001);
+    }
+
+    #[test]
+    fn no_impact_when_moving_apart() {
+        assert_eq!(
+            None,
+            rectangle(0.0, 0.0, 10.0, 10.0).time_of_impact(
+                linear(-10.0, 0.0),
+                &rectangle(20.0, 0.0, 10.0, 10.0),
+                Motion::default(),
+                10.0,
+            )
+        );
+    }
+
+    #[test]
+    fn no_impact_when_passing_by() {
+        assert_eq!(
+            None,
+            rectangle(0.0, 0.0, 10.0, 10.0).time_of_impact(
+                linear(100.0, 0.0),
+                &rectangle(20.0, 15.0, 10.0, 10.0),
+                Motion::default(),
+                1.0,
+            )
+        );
+    }
+
+    #[test]
+    fn no_impact_after_time_step() {
+        assert_eq!(
+            None,
+            rectangle(0.0, 0.0, 10.0, 10.0).time_of_impact(
+                linear(10.0, 0.0),
+                &rectangle(20.0, 0.0, 10.0, 10.0),
+                Motion::default(),
+                0.5,
+            )
+        );
+    }
+
+    #[test]
+    fn overlapping_polygons_have_impact_at_start() {
+        let impact = rectangle(0.0, 0.0, 10.0, 10.0)
+            .time_of_impact(
+                linear(10.0, 0.0),
+                &rectangle(8.0, 0.0, 10.0, 10.0),
+                Motion::default(),
+                1.0,
+            )
+            .unwrap();
+
+        assert_eq!(0.0, impact.time);
+        assert_nearly_eq!(1.0, impact.normal.x);
+    }
+
+    #[test]
+    fn rotating_bar_hits_nearest_corner() {
+        let bar = rectangle(-5.0, -0.1, 10.0, 0.2);
+        let spinning = Motion {
+            angular_velocity: FRAC_PI_2,
+            ..Motion::default()
+        };
+        let obstacle = rectangle(3.0, -4.0, 1.0, 1.0);
+
+        assert!(!bar.intersects(&obstacle));
+        let impact = bar
+            .time_of_impact(spinning, &obstacle, Motion::default(), 1.0)
+            .unwrap();
+
+        // The corner at (4, -3) is hit by the bar's edge, which lies 0.1 off its axis
+        let expected_angle = 3.0f64.atan2(4.0) - (0.1f64 / 5.0).asin();
+        assert_nearly_eq!(expected_angle / FRAC_PI_2, impact.time, 0.000_001);
+    }
+
+    #[test]
+    fn bar_rotating_away_from_obstacle_has_no_impact() {
+        let bar = rectangle(-5.0, -0.1, 10.0, 0.2);
+        let spinning_backwards = Motion {
+            angular_velocity: -FRAC_PI_2,
+            ..Motion::default()
+        };
+        let obstacle = rectangle(3.0, -4.0, 1.0, 1.0);
+
+        assert_eq!(
+            None,
+            bar.time_of_impact(spinning_backwards, &obstacle, Motion::default(), 1.0)
+        );
+    }
+
+    #[test]
+    fn fast_spin_is_not_limited_to_one_turn() {
+        let bar = rectangle(-5.0, -0.1, 10.0, 0.2);
+        let spinning = Motion {
+            angular_velocity: 10.0 * TAU + FRAC_PI_2,
+            ..Motion::default()
+        };
+        let obstacle = rectangle(3.0, -4.0, 1.0, 1.0);
+
+        let impact = bar
+            .time_of_impact(spinning, &obstacle, Motion::default(), 1.0)
+            .unwrap();
+
+        let expected_angle = 3.0f64.atan2(4.0) - (0.1f64 / 5.0).asin();
+        assert_nearly_eq!(
+            expected_angle / (10.0 * TAU + FRAC_PI_2),
+            impact.time,
+            0.000_001
+        );
+    }
+
+    #[test]
+    fn centroid_of_rectangle_is_its_center() {
+        let centroid = centroid(&rectangle(2.0, 4.0, 6.0, 2.0).counter_clockwise_vertices());
+
+        assert_nearly_eq!(5.0, centroid.x);
+        assert_nearly_eq!(5.0, centroid.y);
+    }
+}
@@ -0,0 +1,451 @@
+//! Types relating to 2D polygons with holes cut out of them
+
+use crate::{Aabb, Point, Rotation, Segment, SegmentIntersection, SimplePolygon, Vector};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+/// A polygon consisting of an exterior ring with any number of holes cut out of it
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │   ┌─────────────────────┐ ← Exterior
+/// │   │   ┌───┐             │
+/// │   │   └───┘ ← Hole      │
+/// │   │             ┌───┐   │
+/// │   │             └───┘   │
+/// │   └─────────────────────┘
+/// y
+/// ```
+///
+/// Deserialization goes through [`PolygonWithHoles::try_new`],
+/// so invalid holes are rejected.
+///
+/// [`PolygonWithHoles::try_new`]: ./struct.PolygonWithHoles.html#method.try_new
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedPolygonWithHoles")]
+pub struct PolygonWithHoles {
+    exterior: SimplePolygon,
+    holes: Vec<SimplePolygon>,
+}
+
+/// Mirrors the serialized representation of a [`PolygonWithHoles`]
+/// before its holes have been validated
+#[derive(Deserialize)]
+#[serde(rename = "PolygonWithHoles")]
+struct UncheckedPolygonWithHoles {
+    exterior: SimplePolygon,
+    holes: Vec<SimplePolygon>,
+}
+
+impl TryFrom<UncheckedPolygonWithHoles> for PolygonWithHoles {
+    type Error = PolygonWithHolesError;
+
+    fn try_from(unchecked: UncheckedPolygonWithHoles) -> Result<Self, Self::Error> {
+        PolygonWithHoles::try_new(unchecked.exterior, unchecked.holes)
+    }
+}
+
+impl PolygonWithHoles {
+    /// Creates a new [`PolygonWithHoles`] by cutting `holes` out of `exterior`.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, PolygonWithHoles, SimplePolygon};
+    ///
+    /// let square = |x: f64, y: f64, size: f64| {
+    ///     SimplePolygon::try_new(vec![
+    ///         Point { x, y },
+    ///         Point { x: x + size, y },
+    ///         Point {
+    ///             x: x + size,
+    ///             y: y + size,
+    ///         },
+    ///         Point { x, y: y + size },
+    ///     ])
+    ///     .unwrap()
+    /// };
+    ///
+    /// let lake = PolygonWithHoles::try_new(square(0.0, 0.0, 10.0), vec![square(2.0, 2.0, 2.0)])
+    ///     .expect("Hole is not inside exterior");
+    /// assert_eq!(96.0, lake.area());
+    /// ```
+    ///
+    /// Holes may touch the exterior or each other at single points,
+    /// but must not share edges or cross them.
+    ///
+    /// # Errors
+    /// Returns a [`PolygonWithHolesError`] if a hole is not inside
+    /// the exterior, or if two holes overlap.
+    ///
+    /// [`PolygonWithHoles`]: ./struct.PolygonWithHoles.html
+    /// [`PolygonWithHolesError`]: ./enum.PolygonWithHolesError.html
+    pub fn try_new(
+        exterior: SimplePolygon,
+        holes: Vec<SimplePolygon>,
+    ) -> Result<Self, PolygonWithHolesError> {
+        if let Some(index) = holes.iter().position(|hole| !is_inside(hole, &exterior)) {
+            return Err(PolygonWithHolesError::HoleOutsideExterior { index });
+        }
+
+        let overlapping_holes = (0..holes.len())
+            .flat_map(|first| (first + 1..holes.len()).map(move |second| (first, second)))
+            .find(|&(first, second)| rings_overlap(&holes[first], &holes[second]));
+        if let Some((first, second)) = overlapping_holes {
+            return Err(PolygonWithHolesError::OverlappingHoles { first, second });
+        }
+
+        Ok(Self { exterior, holes })
+    }
+
+    /// Creates a new [`PolygonWithHoles`] without validating the holes.
+    /// Used for results that are valid by construction.
+    ///
+    /// [`PolygonWithHoles`]: ./struct.PolygonWithHoles.html
+    pub(crate) fn new_unchecked(exterior: SimplePolygon, holes: Vec<SimplePolygon>) -> Self {
+        Self { exterior, holes }
+    }
+
+    /// Returns the ring enclosing the polygon
+    pub fn exterior(&self) -> &SimplePolygon {
+        &self.exterior
+    }
+
+    /// Returns the rings that are cut out of the polygon
+    pub fn holes(&self) -> &[SimplePolygon] {
+        &self.holes
+    }
+
+    /// Iterates over the vertices of the exterior, followed by the vertices of each hole
+    pub fn vertices(&self) -> impl Iterator<Item = Point> + '_ {
+        self.exterior
+            .vertices()
+            .iter()
+            .chain(self.holes.iter().flat_map(SimplePolygon::vertices))
+            .copied()
+    }
+
+    /// Returns the area enclosed by the exterior, minus the area of the holes
+    pub fn area(&self) -> f64 {
+        self.exterior.area() - self.holes.iter().map(SimplePolygon::area).sum::<f64>()
+    }
+
+    /// Checks if a given point rests inside the polygon or on its boundary.
+    /// Points inside a hole are not contained, but points on a hole's boundary are.
+    pub fn contains_point(&self, point: Point) -> bool {
+        self.exterior.contains_point(point)
+            && !self.holes.iter().any(|hole| {
+                hole.contains_point(point)
+                    && !hole.segments().any(|segment| segment.contains_point(point))
+            })
+    }
+
+    /// Returns an [`Aabb`] which fully contains this polygon.
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    pub fn aabb(&self) -> Aabb {
+        self.exterior.aabb()
+    }
+
+    /// Apply translation specified by `translation`, represented as
+    /// a relative point
+    pub fn translate(&self, translation: Point) -> Self {
+        Self {
+            exterior: self.exterior.translate(translation),
+            holes: self
+                .holes
+                .iter()
+                .map(|hole| hole.translate(translation))
+                .collect(),
+        }
+    }
+
+    /// Rotate polygon by a `rotation` around a `point`.
+    /// See [`Radians`] for the direction of the rotation.
+    ///
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    pub fn rotate_around_point<R>(&self, rotation: R, point: Point) -> Self
+    where
+        R: Into<Rotation>,
+    {
+        let rotation = rotation.into();
+        Self {
+            exterior: self.exterior.rotate_around_point(rotation, point),
+            holes: self
+                .holes
+                .iter()
+                .map(|hole| hole.rotate_around_point(rotation, point))
+                .collect(),
+        }
+    }
+}
+
+impl From<SimplePolygon> for PolygonWithHoles {
+    fn from(exterior: SimplePolygon) -> Self {
+        Self {
+            exterior,
+            holes: Vec::new(),
+        }
+    }
+}
+
+/// Relative tolerance under which an intersection is considered to lie on a vertex
+const TOUCHING_TOLERANCE: f64 = 0.000_000_001;
+
+/// Checks if `inner` lies inside `outer`, touching its boundary at most at single points
+fn is_inside(inner: &SimplePolygon, outer: &SimplePolygon) -> bool {
+    !boundaries_cross(inner, outer) && sample_points(inner).all(|point| outer.contains_point(point))
+}
+
+/// Checks if the areas enclosed by two rings overlap.
+/// Rings that only touch at single points don't overlap.
+fn rings_overlap(first: &SimplePolygon, second: &SimplePolygon) -> bool {
+    boundaries_cross(first, second)
+        || sample_points(second).any(|point| is_strictly_inside(point, first))
+        || sample_points(first).any(|point| is_strictly_inside(point, second))
+}
+
+/// Checks if the boundaries of two rings cross each other or share an edge.
+/// Boundaries that meet at a vertex of either ring only touch.
+fn boundaries_cross(first: &SimplePolygon, second: &SimplePolygon) -> bool {
+    first.segments().any(|first_segment| {
+        second.segments().any(
+            |second_segment| match first_segment.intersection(&second_segment) {
+                None => false,
+                Some(SegmentIntersection::Point(point)) => {
+                    !is_endpoint(&first_segment, point) && !is_endpoint(&second_segment, point)
+                }
+                Some(SegmentIntersection::Overlap(_)) => true,
+            },
+        )
+    })
+}
+
+fn is_endpoint(segment: &Segment, point: Point) -> bool {
+    let tolerance = TOUCHING_TOLERANCE * segment.length();
+    Vector::from(point - segment.start).magnitude() <= tolerance
+        || Vector::from(point - segment.end).magnitude() <= tolerance
+}
+
+/// Returns the vertices of a ring and the midpoints of its edges
+fn sample_points(ring: &SimplePolygon) -> impl Iterator<Item = Point> + '_ {
+    ring.vertices()
+        .iter()
+        .copied()
+        .chain(ring.segments().map(|segment| segment.midpoint()))
+}
+
+fn is_strictly_inside(point: Point, ring: &SimplePolygon) -> bool {
+    ring.contains_point(point) && !ring.segments().any(|segment| segment.contains_point(point))
+}
+
+/// The reason why a [`PolygonWithHoles`] instance could not be created
+///
+/// [`PolygonWithHoles`]: ./struct.PolygonWithHoles.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PolygonWithHolesError {
+    /// A hole was not inside the exterior, or shared an edge with it
+    HoleOutsideExterior {
+        /// The index of the offending hole
+        index: usize,
+    },
+    /// Two holes overlapped each other or shared an edge
+    OverlappingHoles {
+        /// The index of the first overlapping hole
+        first: usize,
+        /// The index of the second overlapping hole
+        second: usize,
+    },
+}
+
+impl fmt::Display for PolygonWithHolesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolygonWithHolesError::HoleOutsideExterior { index } => {
+                write!(f, "Hole at index {} is not inside the exterior", index)
+            }
+            PolygonWithHolesError::OverlappingHoles { first, second } => {
+                write!(f, "Holes at indices {} and {} overlap", first, second)
+            }
+        }
+    }
+}
+
+impl Error for PolygonWithHolesError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Radians;
+    use nearly_eq::assert_nearly_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn square(x: f64, y: f64, size: f64) -> SimplePolygon {
+        SimplePolygon::try_new(vec![
+            Point { x, y },
+            Point { x: x + size, y },
+            Point {
+                x: x + size,
+                y: y + size,
+            },
+            Point { x, y: y + size },
+        ])
+        .unwrap()
+    }
+
+    fn polygon_with_holes() -> PolygonWithHoles {
+        PolygonWithHoles::try_new(
+            square(0.0, 0.0, 10.0),
+            vec![square(1.0, 1.0, 2.0), square(5.0, 5.0, 3.0)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn try_new_errors_for_hole_outside_exterior() {
+        assert_eq!(
+            Err(PolygonWithHolesError::HoleOutsideExterior { index: 1 }),
+            PolygonWithHoles::try_new(
+                square(0.0, 0.0, 10.0),
+                vec![square(1.0, 1.0, 2.0), square(20.0, 20.0, 2.0)],
+            )
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_hole_crossing_exterior() {
+        assert_eq!(
+            Err(PolygonWithHolesError::HoleOutsideExterior { index: 0 }),
+            PolygonWithHoles::try_new(square(0.0, 0.0, 10.0), vec![square(8.0, 8.0, 4.0)])
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_hole_containing_exterior() {
+        assert_eq!(
+            Err(PolygonWithHolesError::HoleOutsideExterior { index: 0 }),
+            PolygonWithHoles::try_new(square(2.0, 2.0, 2.0), vec![square(0.0, 0.0, 10.0)])
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_overlapping_holes() {
+        assert_eq!(
+            Err(PolygonWithHolesError::OverlappingHoles {
+                first: 0,
+                second: 1
+            }),
+            PolygonWithHoles::try_new(
+                square(0.0, 0.0, 10.0),
+                vec![square(1.0, 1.0, 4.0), square(3.0, 3.0, 4.0)],
+            )
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_nested_holes() {
+        assert_eq!(
+            Err(PolygonWithHolesError::OverlappingHoles {
+                first: 0,
+                second: 1
+            }),
+            PolygonWithHoles::try_new(
+                square(0.0, 0.0, 10.0),
+                vec![square(1.0, 1.0, 8.0), square(3.0, 3.0, 2.0)],
+            )
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_hole_sharing_edge_with_exterior() {
+        assert_eq!(
+            Err(PolygonWithHolesError::HoleOutsideExterior { index: 0 }),
+            PolygonWithHoles::try_new(square(0.0, 0.0, 10.0), vec![square(0.0, 2.0, 2.0)])
+        );
+    }
+
+    #[test]
+    fn try_new_accepts_hole_touching_exterior_at_vertex() {
+        let diamond = SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 5.0 },
+            Point { x: 3.0, y: 2.0 },
+            Point { x: 6.0, y: 5.0 },
+            Point { x: 3.0, y: 8.0 },
+        ])
+        .unwrap();
+
+        let polygon = PolygonWithHoles::try_new(square(0.0, 0.0, 10.0), vec![diamond]).unwrap();
+
+        assert_nearly_eq!(100.0 - 18.0, polygon.area());
+    }
+
+    #[test]
+    fn try_new_accepts_holes_touching_at_vertex() {
+        assert!(PolygonWithHoles::try_new(
+            square(0.0, 0.0, 10.0),
+            vec![square(1.0, 1.0, 2.0), square(3.0, 3.0, 2.0)],
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn area_subtracts_holes() {
+        assert_nearly_eq!(100.0 - 4.0 - 9.0, polygon_with_holes().area());
+    }
+
+    #[test]
+    fn contains_point_outside_of_holes() {
+        assert!(polygon_with_holes().contains_point(Point { x: 4.0, y: 4.0 }));
+    }
+
+    #[test]
+    fn does_not_contain_point_inside_hole() {
+        assert!(!polygon_with_holes().contains_point(Point { x: 2.0, y: 2.0 }));
+    }
+
+    #[test]
+    fn contains_point_on_hole_boundary() {
+        assert!(polygon_with_holes().contains_point(Point { x: 3.0, y: 2.0 }));
+    }
+
+    #[test]
+    fn does_not_contain_point_outside_exterior() {
+        assert!(!polygon_with_holes().contains_point(Point { x: 11.0, y: 2.0 }));
+    }
+
+    #[test]
+    fn aabb_is_aabb_of_exterior() {
+        let expected_aabb = Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap();
+        assert_eq!(expected_aabb, polygon_with_holes().aabb());
+    }
+
+    #[test]
+    fn translates_holes() {
+        let translation = Point { x: 10.0, y: -5.0 };
+        let translated_polygon = polygon_with_holes().translate(translation);
+
+        assert_eq!(&square(10.0, -5.0, 10.0), translated_polygon.exterior());
+        assert_eq!(&square(11.0, -4.0, 2.0), &translated_polygon.holes()[0]);
+        assert!(!translated_polygon.contains_point(Point { x: 12.0, y: -3.0 }));
+    }
+
+    #[test]
+    fn rotates_holes() {
+        let rotation = Radians::try_new(FRAC_PI_2).unwrap();
+        let rotated_polygon = polygon_with_holes().rotate_around_point(rotation, Point::default());
+
+        assert_nearly_eq!(rotated_polygon.area(), polygon_with_holes().area());
+        let rotated_hole_center =
+            Point { x: 2.0, y: 2.0 }.rotate_around_point(rotation, Point::default());
+        assert!(!rotated_polygon.contains_point(rotated_hole_center));
+    }
+
+    #[test]
+    fn can_be_created_from_simple_polygon() {
+        let polygon = PolygonWithHoles::from(square(0.0, 0.0, 10.0));
+        assert!(polygon.holes().is_empty());
+        assert_nearly_eq!(100.0, polygon.area());
+    }
+}
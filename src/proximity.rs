@@ -0,0 +1,522 @@
+//! Distance and penetration queries between convex shapes
+
+use crate::{ConvexHull, Manifold, Point, Vector};
+use std::cmp::Ordering;
+
+/// Relative tolerance under which the distance between two shapes
+/// stops improving, or under which shapes are considered to touch
+const TOLERANCE: f64 = 0.000_000_001;
+
+/// Upper bound for the number of refinement steps of GJK and EPA,
+/// which protects against cycling due to rounding errors
+const MAX_ITERATIONS: usize = 64;
+
+/// A convex shape that is described by its [support function],
+/// i.e. by the point that lies furthest in any given direction.
+///
+/// This is all that is needed to calculate the [`Proximity`] of two shapes.
+///
+/// [support function]: https://en.wikipedia.org/wiki/Support_function
+/// [`Proximity`]: ./enum.Proximity.html
+pub trait Support {
+    /// Returns a point of the shape that lies furthest in `direction`.
+    /// `direction` doesn't need to be a unit vector.
+    fn support_point(&self, direction: Vector) -> Point;
+
+    /// Calculates how far this shape is away from `other` using the
+    /// [Gilbert–Johnson–Keerthi distance algorithm] or, if the shapes overlap,
+    /// how deeply they penetrate each other using the Expanding Polytope Algorithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Aabb, Point, PolygonBuilder, Proximity, Support};
+    ///
+    /// let triangle = PolygonBuilder::default()
+    ///     .vertex(0.0, 0.0)
+    ///     .vertex(4.0, 0.0)
+    ///     .vertex(0.0, 4.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let aabb = Aabb::try_new((6.0, -2.0), (10.0, 2.0)).unwrap();
+    ///
+    /// match triangle.proximity(&aabb) {
+    ///     Proximity::Apart(closest_points) => {
+    ///         assert_eq!(2.0, closest_points.distance);
+    ///         assert_eq!(Point { x: 4.0, y: 0.0 }, closest_points.first);
+    ///         assert_eq!(Point { x: 6.0, y: 0.0 }, closest_points.second);
+    ///     }
+    ///     Proximity::Overlapping(_) => panic!("Shapes overlap"),
+    /// }
+    /// ```
+    ///
+    /// [Gilbert–Johnson–Keerthi distance algorithm]: https://en.wikipedia.org/wiki/Gilbert%E2%80%93Johnson%E2%80%93Keerthi_distance_algorithm
+    fn proximity<Other>(&self, other: &Other) -> Proximity
+    where
+        Self: Sized,
+        Other: Support + ?Sized,
+    {
+        proximity(self, other)
+    }
+}
+
+/// Describes how two convex shapes are positioned relative to each other
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Proximity {
+    /// The shapes don't touch
+    Apart(ClosestPoints),
+    /// The shapes touch or overlap. The [`Manifold`]'s normal points
+    /// from the first shape towards the second one. Touching shapes
+    /// have a penetration depth of zero.
+    ///
+    /// If the shapes' Minkowski difference doesn't cover any area,
+    /// e.g. when two points coincide, there is no meaningful normal
+    /// and it is the zero vector.
+    ///
+    /// [`Manifold`]: ./struct.Manifold.html
+    Overlapping(Manifold),
+}
+
+impl Proximity {
+    /// Returns the distance between the shapes, which is zero if they overlap
+    pub fn distance(&self) -> f64 {
+        match self {
+            Proximity::Apart(closest_points) => closest_points.distance,
+            Proximity::Overlapping(_) => 0.0,
+        }
+    }
+}
+
+/// The points at which two shapes that are apart come closest to each other
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ClosestPoints {
+    /// The point on the first shape that is closest to the second shape
+    pub first: Point,
+    /// The point on the second shape that is closest to the first shape
+    pub second: Point,
+    /// The distance between the two points
+    pub distance: f64,
+}
+
+/// A point of the Minkowski difference of two shapes,
+/// together with the points of the shapes it was created from
+#[derive(Debug, Copy, Clone)]
+struct SupportPoint {
+    difference: Vector,
+    first: Point,
+    second: Point,
+}
+
+fn support_of_difference<First, Second>(
+    first: &First,
+    second: &Second,
+    direction: Vector,
+) -> SupportPoint
+where
+    First: Support + ?Sized,
+    Second: Support + ?Sized,
+{
+    let first_point = first.support_point(direction);
+    let second_point = second.support_point(direction.negative());
+    SupportPoint {
+        difference: Vector::from(first_point - second_point),
+        first: first_point,
+        second: second_point,
+    }
+}
+
+/// Searches the point of the shapes' Minkowski difference that is closest to the origin
+/// by refining a simplex of up to three of its points (GJK).
+/// The shapes overlap exactly when the difference contains the origin.
+fn proximity<First, Second>(first: &First, second: &Second) -> Proximity
+where
+    First: Support + ?Sized,
+    Second: Support + ?Sized,
+{
+    let initial_point = support_of_difference(first, second, Vector { x: 1.0, y: 0.0 });
+    let mut scale = initial_point.difference.magnitude().max(1.0);
+    let mut simplex = vec![initial_point];
+
+    let mut iteration = 0;
+    loop {
+        let weights = match reduce_to_closest_feature(&mut simplex) {
+            Some(weights) => weights,
+            None => return penetration(first, second, &simplex, scale),
+        };
+        let closest_point = weighted_difference(&simplex, &weights);
+        if closest_point.magnitude() <= TOLERANCE * scale {
+            return penetration(first, second, &simplex, scale);
+        }
+
+        let new_point = support_of_difference(first, second, closest_point.negative());
+        scale = scale.max(new_point.difference.magnitude());
+        let squared_distance = closest_point.dot_product(closest_point);
+        let improvement = squared_distance - closest_point.dot_product(new_point.difference);
+        let is_converged = improvement <= TOLERANCE * squared_distance
+            || simplex
+                .iter()
+                .any(|point| point.difference == new_point.difference);
+        iteration += 1;
+        if is_converged || iteration == MAX_ITERATIONS {
+            return Proximity::Apart(closest_points(&simplex, &weights));
+        }
+        simplex.push(new_point);
+    }
+}
+
+/// Reduces the simplex to the smallest part of it that contains the point closest
+/// to the origin, and returns the barycentric weights of that point.
+/// Returns `None` if the simplex is a triangle that contains the origin.
+fn reduce_to_closest_feature(simplex: &mut Vec<SupportPoint>) -> Option<Vec<f64>> {
+    match simplex.len() {
+        1 => Some(vec![1.0]),
+        2 => {
+            let (reduced_simplex, weights) = closest_on_segment(simplex[0], simplex[1]);
+            *simplex = reduced_simplex;
+            Some(weights)
+        }
+        _ => {
+            let (first, second, third) = (simplex[0], simplex[1], simplex[2]);
+            if triangle_contains_origin(first.difference, second.difference, third.difference) {
+                return None;
+            }
+
+            let (reduced_simplex, weights) = vec![(first, second), (second, third), (third, first)]
+                .into_iter()
+                .map(|(start, end)| closest_on_segment(start, end))
+                .min_by(
+                    |(first_simplex, first_weights), (second_simplex, second_weights)| {
+                        weighted_difference(first_simplex, first_weights)
+                            .magnitude()
+                            .partial_cmp(
+                                &weighted_difference(second_simplex, second_weights).magnitude(),
+                            )
+                            .unwrap_or(Ordering::Equal)
+                    },
+                )
+                // Safe unwrap: A triangle has three edges
+                .unwrap();
+            *simplex = reduced_simplex;
+            Some(weights)
+        }
+    }
+}
+
+/// Returns the point of the Minkowski difference with the given barycentric weights
+fn weighted_difference(simplex: &[SupportPoint], weights: &[f64]) -> Vector {
+    simplex
+        .iter()
+        .zip(weights)
+        .fold(Vector::default(), |sum, (point, &weight)| {
+            sum + point.difference * weight
+        })
+}
+
+fn closest_on_segment(start: SupportPoint, end: SupportPoint) -> (Vec<SupportPoint>, Vec<f64>) {
+    let direction = end.difference - start.difference;
+    let squared_length = direction.dot_product(direction);
+    if squared_length == 0.0 {
+        return (vec![start], vec![1.0]);
+    }
+
+    let factor = -start.difference.dot_product(direction) / squared_length;
+    if factor <= 0.0 {
+        (vec![start], vec![1.0])
+    } else if factor >= 1.0 {
+        (vec![end], vec![1.0])
+    } else {
+        (vec![start, end], vec![1.0 - factor, factor])
+    }
+}
+
+/// Checks if the origin lies inside or on the boundary of a triangle of either winding
+fn triangle_contains_origin(first: Vector, second: Vector, third: Vector) -> bool {
+    let sides = [
+        (second - first).cross_product(first.negative()),
+        (third - second).cross_product(second.negative()),
+        (first - third).cross_product(third.negative()),
+    ];
+    sides.iter().all(|&side| side >= 0.0) || sides.iter().all(|&side| side <= 0.0)
+}
+
+fn closest_points(simplex: &[SupportPoint], weights: &[f64]) -> ClosestPoints {
+    let weighted_sum = |point_of: fn(&SupportPoint) -> Point| {
+        let sum = simplex
+            .iter()
+            .zip(weights)
+            .fold(Vector::default(), |sum, (point, &weight)| {
+                sum + Vector::from(point_of(point)) * weight
+            });
+        Point::from(sum)
+    };
+    let first = weighted_sum(|point| point.first);
+    let second = weighted_sum(|point| point.second);
+    ClosestPoints {
+        first,
+        second,
+        distance: Vector::from(second - first).magnitude(),
+    }
+}
+
+/// Finds the edge of the shapes' Minkowski difference that is closest to the origin
+/// by expanding a polygon inside of the difference towards it (EPA).
+/// The simplex found by GJK is padded with further support points, so that the polygon
+/// covers an area around the origin whenever the difference does.
+fn penetration<First, Second>(
+    first: &First,
+    second: &Second,
+    simplex: &[SupportPoint],
+    scale: f64,
+) -> Proximity
+where
+    First: Support + ?Sized,
+    Second: Support + ?Sized,
+{
+    let mut directions = vec![
+        Vector { x: 1.0, y: 0.0 },
+        Vector { x: -1.0, y: 0.0 },
+        Vector { x: 0.0, y: 1.0 },
+        Vector { x: 0.0, y: -1.0 },
+    ];
+    if let [start, end] = simplex {
+        let normal = (end.difference - start.difference).normal();
+        directions.push(normal);
+        directions.push(normal.negative());
+    }
+    let points: Vec<_> = simplex
+        .iter()
+        .copied()
+        .chain(
+            directions
+                .into_iter()
+                .map(|direction| support_of_difference(first, second, direction)),
+        )
+        .map(|point| Point::from(point.difference))
+        .collect();
+    let mut polytope: Vec<Vector> = ConvexHull::try_new(points)
+        .map(|convex_hull| convex_hull.map(Vector::from).collect())
+        .unwrap_or_default();
+    if polytope.len() < 3 {
+        return Proximity::Overlapping(Manifold {
+            normal: Vector::default(),
+            depth: 0.0,
+        });
+    }
+
+    let mut iteration = 0;
+    loop {
+        let (index, normal, distance) = (0..polytope.len())
+            .map(|index| {
+                let start = polytope[index];
+                let end = polytope[(index + 1) % polytope.len()];
+                // The polytope is ordered counter clockwise,
+                // so the outward normal is on the right of each edge
+                let normal = (end - start).normal().negative().unit();
+                (index, normal, normal.dot_product(start))
+            })
+            .min_by(|(_, _, first_distance), (_, _, second_distance)| {
+                first_distance
+                    .partial_cmp(second_distance)
+                    .unwrap_or(Ordering::Equal)
+            })
+            // Safe unwrap: The polytope has at least three edges
+            .unwrap();
+
+        let new_point = support_of_difference(first, second, normal).difference;
+        let improvement = new_point.dot_product(normal) - distance;
+        iteration += 1;
+        if improvement <= TOLERANCE * scale || iteration == MAX_ITERATIONS {
+            return Proximity::Overlapping(Manifold {
+                normal,
+                depth: distance.max(0.0),
+            });
+        }
+        polytope.insert(index + 1, new_point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aabb, Polygon, PolygonBuilder, Radians};
+    use nearly_eq::assert_nearly_eq;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        PolygonBuilder::default()
+            .vertex(x, y)
+            .vertex(x + size, y)
+            .vertex(x + size, y + size)
+            .vertex(x, y + size)
+            .build()
+            .unwrap()
+    }
+
+    fn closest_points(proximity: Proximity) -> ClosestPoints {
+        match proximity {
+            Proximity::Apart(closest_points) => closest_points,
+            Proximity::Overlapping(manifold) => panic!("Shapes overlap: {:?}", manifold),
+        }
+    }
+
+    fn manifold(proximity: Proximity) -> Manifold {
+        match proximity {
+            Proximity::Overlapping(manifold) => manifold,
+            Proximity::Apart(closest_points) => panic!("Shapes are apart: {:?}", closest_points),
+        }
+    }
+
+    #[test]
+    fn distance_between_squares_facing_each_other() {
+        let closest_points =
+            closest_points(square(0.0, 0.0, 10.0).proximity(&square(15.0, 2.0, 4.0)));
+
+        assert_nearly_eq!(5.0, closest_points.distance);
+        assert_nearly_eq!(10.0, closest_points.first.x);
+        assert_nearly_eq!(15.0, closest_points.second.x);
+        assert_nearly_eq!(closest_points.first.y, closest_points.second.y);
+    }
+
+    #[test]
+    fn distance_between_diagonal_corners() {
+        let closest_points =
+            closest_points(square(0.0, 0.0, 10.0).proximity(&square(13.0, 14.0, 2.0)));
+
+        assert_nearly_eq!(5.0, closest_points.distance);
+        assert_nearly_eq!(10.0, closest_points.first.x);
+        assert_nearly_eq!(10.0, closest_points.first.y);
+        assert_nearly_eq!(13.0, closest_points.second.x);
+        assert_nearly_eq!(14.0, closest_points.second.y);
+    }
+
+    #[test]
+    fn distance_between_rotated_polygons() {
+        let rotated_square = square(-1.0, -1.0, 2.0).rotate_around_point(
+            Radians::try_new(std::f64::consts::FRAC_PI_4).unwrap(),
+            Point::default(),
+        );
+        let diamond = rotated_square.translate(Point { x: 20.0, y: 5.0 });
+
+        let distance = square(0.0, 0.0, 10.0).proximity(&diamond).distance();
+
+        assert_nearly_eq!(10.0 - std::f64::consts::SQRT_2, distance);
+    }
+
+    #[test]
+    fn distance_from_point_to_polygon() {
+        let closest_points =
+            closest_points(Point { x: 5.0, y: -3.0 }.proximity(&square(0.0, 0.0, 10.0)));
+
+        assert_nearly_eq!(3.0, closest_points.distance);
+        assert_nearly_eq!(5.0, closest_points.second.x);
+        assert_nearly_eq!(0.0, closest_points.second.y);
+    }
+
+    #[test]
+    fn distance_from_aabb_to_polygon_is_symmetric() {
+        let aabb = Aabb::try_new((12.0, 12.0), (14.0, 20.0)).unwrap();
+        let polygon = square(0.0, 0.0, 10.0);
+
+        assert_nearly_eq!(
+            aabb.proximity(&polygon).distance(),
+            polygon.proximity(&aabb).distance()
+        );
+        assert_nearly_eq!(8.0f64.sqrt(), aabb.proximity(&polygon).distance());
+    }
+
+    #[test]
+    fn distance_matches_closest_vertex_to_edge_distance() {
+        let hexagon = PolygonBuilder::default()
+            .vertex(0.0, 0.0)
+            .vertex(4.0, -1.0)
+            .vertex(7.0, 2.0)
+            .vertex(6.0, 6.0)
+            .vertex(2.0, 7.0)
+            .vertex(-1.0, 4.0)
+            .build()
+            .unwrap();
+        let triangle = PolygonBuilder::default()
+            .vertex(0.0, 0.0)
+            .vertex(3.0, 1.0)
+            .vertex(1.0, 3.0)
+            .build()
+            .unwrap();
+
+        for index in 0..24 {
+            let angle = f64::from(index) * std::f64::consts::PI / 12.0;
+            let offset = Point {
+                x: 12.0 * angle.cos(),
+                y: 12.0 * angle.sin(),
+            };
+            let moved_triangle = triangle.translate(offset);
+
+            let vertex_to_edge_distance = |vertices: &Polygon, edges: &Polygon| {
+                vertices
+                    .vertices()
+                    .iter()
+                    .flat_map(|&vertex| {
+                        edges.segments().map(move |segment| {
+                            Vector::from(vertex - segment.closest_point(vertex)).magnitude()
+                        })
+                    })
+                    .fold(f64::INFINITY, f64::min)
+            };
+            let expected_distance = vertex_to_edge_distance(&hexagon, &moved_triangle)
+                .min(vertex_to_edge_distance(&moved_triangle, &hexagon));
+
+            assert_nearly_eq!(
+                expected_distance,
+                hexagon.proximity(&moved_triangle).distance(),
+                0.000_001
+            );
+        }
+    }
+
+    #[test]
+    fn penetration_matches_collision_manifold() {
+        let first = square(0.0, 0.0, 10.0);
+        let second = first.translate(Point { x: 8.0, y: 1.0 });
+
+        let manifold = manifold(first.proximity(&second));
+        let expected_manifold = first.collision_manifold(&second).unwrap();
+
+        assert_nearly_eq!(expected_manifold.depth, manifold.depth);
+        assert_nearly_eq!(expected_manifold.normal.x, manifold.normal.x);
+        assert_nearly_eq!(expected_manifold.normal.y, manifold.normal.y);
+    }
+
+    #[test]
+    fn penetration_of_concentric_squares() {
+        let manifold = manifold(square(0.0, 0.0, 10.0).proximity(&square(2.0, 2.0, 6.0)));
+
+        assert_nearly_eq!(8.0, manifold.depth);
+        assert_nearly_eq!(1.0, manifold.normal.magnitude());
+    }
+
+    #[test]
+    fn point_inside_polygon_penetrates_up_to_nearest_edge() {
+        let manifold = manifold(square(0.0, 0.0, 10.0).proximity(&Point { x: 9.0, y: 4.0 }));
+
+        assert_nearly_eq!(1.0, manifold.depth);
+        assert_nearly_eq!(1.0, manifold.normal.x);
+        assert_nearly_eq!(0.0, manifold.normal.y);
+    }
+
+    #[test]
+    fn touching_shapes_overlap_without_depth() {
+        let manifold = manifold(square(0.0, 0.0, 10.0).proximity(&square(10.0, 3.0, 10.0)));
+
+        assert_nearly_eq!(0.0, manifold.depth);
+    }
+
+    #[test]
+    fn coinciding_points_overlap_without_normal() {
+        let point = Point { x: 1.0, y: 2.0 };
+
+        assert_eq!(
+            Proximity::Overlapping(Manifold {
+                normal: Vector::default(),
+                depth: 0.0
+            }),
+            point.proximity(&point)
+        );
+    }
+}
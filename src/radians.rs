@@ -1,14 +1,63 @@
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 use std::error::Error;
 use std::f64::consts::{PI, TAU};
 use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
 
 /// A radian confined to the range of [0.0; 2π)
+///
+/// # Direction of rotation
+/// Every rotation in this crate, e.g. [`Vector::rotate`], [`Point::rotate_around_point`],
+/// [`Polygon::rotate_around_point`], [`Aabb::rotate_around_point`] and [`Transform::rotation`],
+/// turns the positive x axis towards the negative y axis.
+/// Orientation names in this crate follow the mathematical convention described in [`Winding`],
+/// in which this rotation is clockwise, i.e. opposite to the counter clockwise vertex order
+/// of a [`ConvexHull`]. The diagrams of this crate draw y pointing down, where it looks like this:
+///
+/// ```other
+///                (0, -1)
+///                   ↑ ↖
+///                   │   ╲ Rotation by π/2
+///                   │    │
+/// ──────────────────┼────→ (1, 0) ─── x
+///                   │
+///                   │
+///                   y
+/// ```
+///
+/// Deserialization goes through [`Radians::try_new`],
+/// so out-of-range values are rejected.
+///
+/// [`Vector::rotate`]: ./struct.Vector.html#method.rotate
+/// [`Point::rotate_around_point`]: ./struct.Point.html#method.rotate_around_point
+/// [`Polygon::rotate_around_point`]: ./struct.Polygon.html#method.rotate_around_point
+/// [`Aabb::rotate_around_point`]: ./struct.Aabb.html#method.rotate_around_point
+/// [`Transform::rotation`]: ./struct.Transform.html#method.rotation
+/// [`Winding`]: ./enum.Winding.html
+/// [`ConvexHull`]: ./struct.ConvexHull.html
 #[derive(Debug, PartialEq, Copy, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedRadians")]
 pub struct Radians {
     value: f64,
 }
 
+/// Mirrors the serialized representation of [`Radians`]
+/// before its value has been validated
+#[derive(Deserialize)]
+#[serde(rename = "Radians")]
+struct UncheckedRadians {
+    value: f64,
+}
+
+impl TryFrom<UncheckedRadians> for Radians {
+    type Error = RadiansError;
+
+    fn try_from(unchecked: UncheckedRadians) -> Result<Self, Self::Error> {
+        Radians::try_new(unchecked.value)
+    }
+}
+
 impl Radians {
     /// Creates a new instance of [`Radians`].
     ///
@@ -57,18 +106,156 @@ impl Radians {
 
         Radians::try_new(degrees / MAX_DEGREES * MAX_RADIANS)
     }
+
+    /// Creates a new instance of [`Radians`] from an angle of any size,
+    /// wrapping it into the range [0.0; 2π)
+    ///
+    /// ### Errors
+    /// Returns a [`RadiansError`] if the given value is `NaN` or infinite
+    ///
+    /// ### Examples
+    /// ```
+    /// use myelin_geometry::Radians;
+    /// use nearly_eq::assert_nearly_eq;
+    /// use std::f64::consts::PI;
+    ///
+    /// let turn = Radians::try_new_normalized(-0.1).unwrap();
+    /// assert_nearly_eq!(2.0 * PI - 0.1, turn.value());
+    /// ```
+    pub fn try_new_normalized(value: f64) -> Result<Self, RadiansError> {
+        if !value.is_finite() {
+            return Err(RadiansError::NonFinite);
+        }
+
+        let wrapped_value = value.rem_euclid(TAU);
+        // Tiny negative values wrap to exactly 2π due to rounding
+        if wrapped_value >= TAU {
+            Ok(Radians { value: 0.0 })
+        } else {
+            Ok(Radians {
+                value: wrapped_value,
+            })
+        }
+    }
+
+    /// Convert radians to degrees in the range [0.0°; 360°)
+    ///
+    /// ### Examples
+    /// ```
+    /// use myelin_geometry::Radians;
+    /// use nearly_eq::assert_nearly_eq;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// assert_nearly_eq!(90.0, Radians::try_new(FRAC_PI_2).unwrap().to_degrees());
+    /// ```
+    pub fn to_degrees(self) -> f64 {
+        self.value.to_degrees()
+    }
+
+    /// Returns the signed angle by which this heading needs to be rotated
+    /// to reach `other` the shorter way around, in the range (-π; π].
+    /// Positive values turn in the direction of a rotation by a positive angle.
+    ///
+    /// ### Examples
+    /// ```
+    /// use myelin_geometry::Radians;
+    /// use nearly_eq::assert_nearly_eq;
+    ///
+    /// let heading = Radians::try_from_degrees(350.0).unwrap();
+    /// let target = Radians::try_from_degrees(10.0).unwrap();
+    ///
+    /// assert_nearly_eq!(20.0, heading.signed_difference(target).to_degrees());
+    /// assert_nearly_eq!(-20.0, target.signed_difference(heading).to_degrees());
+    /// ```
+    pub fn signed_difference(self, other: Radians) -> f64 {
+        let difference = (other - self).value;
+        if difference > PI {
+            difference - TAU
+        } else {
+            difference
+        }
+    }
+
+    /// Interpolates between this heading and `other`, turning the shorter way around.
+    /// A `factor` of 0 returns this heading, a `factor` of 1 returns `other`.
+    ///
+    /// ### Panics
+    /// Panics if `factor` is `NaN` or infinite.
+    ///
+    /// ### Examples
+    /// ```
+    /// use myelin_geometry::Radians;
+    /// use nearly_eq::assert_nearly_eq;
+    ///
+    /// let start = Radians::try_from_degrees(350.0).unwrap();
+    /// let end = Radians::try_from_degrees(30.0).unwrap();
+    ///
+    /// assert_nearly_eq!(10.0, start.interpolate(end, 0.5).to_degrees());
+    /// ```
+    pub fn interpolate(self, other: Radians, factor: f64) -> Radians {
+        Radians::try_new_normalized(self.value + self.signed_difference(other) * factor)
+            .expect("Interpolated between radians with a non-finite factor")
+    }
+}
+
+impl Add for Radians {
+    type Output = Radians;
+
+    /// Adds two angles, wrapping the sum into the range [0.0; 2π)
+    fn add(self, other: Radians) -> Self::Output {
+        // Safe unwrap: The sum of two finite angles is finite
+        Radians::try_new_normalized(self.value + other.value).unwrap()
+    }
+}
+
+impl Sub for Radians {
+    type Output = Radians;
+
+    /// Subtracts two angles, wrapping the difference into the range [0.0; 2π)
+    fn sub(self, other: Radians) -> Self::Output {
+        // Safe unwrap: The difference of two finite angles is finite
+        Radians::try_new_normalized(self.value - other.value).unwrap()
+    }
+}
+
+impl Neg for Radians {
+    type Output = Radians;
+
+    /// Returns the angle that turns in the opposite direction
+    fn neg(self) -> Self::Output {
+        // Safe unwrap: The negation of a finite angle is finite
+        Radians::try_new_normalized(-self.value).unwrap()
+    }
+}
+
+impl Mul<f64> for Radians {
+    type Output = Radians;
+
+    /// Scales the angle, wrapping the product into the range [0.0; 2π)
+    ///
+    /// ### Panics
+    /// Panics if the product is `NaN` or infinite.
+    fn mul(self, factor: f64) -> Self::Output {
+        Radians::try_new_normalized(self.value * factor)
+            .expect("Multiplied radians by a non-finite factor")
+    }
 }
 
 /// The reason why a [`Radians`] instance could not be created
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum RadiansError {
     /// The given value was not in the range [0.0; 2π)
     OutOfRange,
+    /// The given value was `NaN` or infinite
+    NonFinite,
 }
 
 impl fmt::Display for RadiansError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Given value is not in range [0.0; 2π)")
+        match self {
+            RadiansError::OutOfRange => write!(f, "Given value is not in range [0.0; 2π)"),
+            RadiansError::NonFinite => write!(f, "Given value is NaN or infinite"),
+        }
     }
 }
 
@@ -78,7 +265,8 @@ impl Error for RadiansError {}
 mod tests {
     use super::*;
     use nearly_eq::assert_nearly_eq;
-    use std::f64::consts::PI;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    use std::f64::consts::FRAC_PI_2;
 
     #[test]
     fn radians_new_with_negative_0_point_1_is_none() {
@@ -163,4 +351,177 @@ mod tests {
         let radians = Radians::try_from_degrees(degrees);
         assert!(radians.is_err());
     }
+
+    #[test]
+    fn try_new_normalized_keeps_value_in_range() {
+        let radians = Radians::try_new_normalized(1.5).unwrap();
+        assert_eq!(1.5, radians.value());
+    }
+
+    #[test]
+    fn try_new_normalized_wraps_negative_value() {
+        let radians = Radians::try_new_normalized(-0.1).unwrap();
+        assert_nearly_eq!(TAU - 0.1, radians.value());
+    }
+
+    #[test]
+    fn try_new_normalized_wraps_multiple_turns() {
+        let radians = Radians::try_new_normalized(5.0 * TAU + 1.0).unwrap();
+        assert_nearly_eq!(1.0, radians.value(), 0.000_000_001);
+    }
+
+    #[test]
+    fn try_new_normalized_wraps_tiny_negative_value_to_zero() {
+        let radians = Radians::try_new_normalized(-1e-20).unwrap();
+        assert_eq!(0.0, radians.value());
+    }
+
+    #[test]
+    fn try_new_normalized_errors_for_non_finite_values() {
+        assert_eq!(
+            Err(RadiansError::NonFinite),
+            Radians::try_new_normalized(f64::NAN)
+        );
+        assert_eq!(
+            Err(RadiansError::NonFinite),
+            Radians::try_new_normalized(f64::INFINITY)
+        );
+        assert_eq!(
+            Err(RadiansError::NonFinite),
+            Radians::try_new_normalized(f64::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn to_degrees_works_with_pi() {
+        let radians = Radians::try_new(PI).unwrap();
+        assert_nearly_eq!(180.0, radians.to_degrees());
+    }
+
+    #[test]
+    fn to_degrees_is_inverse_of_try_from_degrees() {
+        let radians = Radians::try_from_degrees(123.0).unwrap();
+        assert_nearly_eq!(123.0, radians.to_degrees());
+    }
+
+    #[test]
+    fn add_wraps_around() {
+        let sum = Radians::try_new(4.0).unwrap() + Radians::try_new(3.0).unwrap();
+        assert_nearly_eq!(7.0 - TAU, sum.value());
+    }
+
+    #[test]
+    fn sub_wraps_around() {
+        let difference = Radians::try_new(1.0).unwrap() - Radians::try_new(3.0).unwrap();
+        assert_nearly_eq!(TAU - 2.0, difference.value());
+    }
+
+    #[test]
+    fn neg_turns_in_opposite_direction() {
+        let radians = Radians::try_new(1.0).unwrap();
+        assert_nearly_eq!(TAU - 1.0, (-radians).value());
+        assert_nearly_eq!(0.0, (radians + -radians).value());
+    }
+
+    #[test]
+    fn neg_of_zero_is_zero() {
+        assert_eq!(Radians::default(), -Radians::default());
+    }
+
+    #[test]
+    fn mul_wraps_around() {
+        let product = Radians::try_new(PI).unwrap() * 2.5;
+        assert_nearly_eq!(FRAC_PI_2, product.value());
+    }
+
+    #[test]
+    fn mul_with_negative_factor_wraps_around() {
+        let product = Radians::try_new(1.0).unwrap() * -0.5;
+        assert_nearly_eq!(TAU - 0.5, product.value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_panics_with_non_finite_factor() {
+        let _ = Radians::try_new(1.0).unwrap() * f64::INFINITY;
+    }
+
+    #[test]
+    fn signed_difference_is_positive_in_direction_of_rotation() {
+        let heading = Radians::try_new(1.0).unwrap();
+        let target = Radians::try_new(2.5).unwrap();
+        assert_nearly_eq!(1.5, heading.signed_difference(target));
+        assert_nearly_eq!(-1.5, target.signed_difference(heading));
+    }
+
+    #[test]
+    fn signed_difference_takes_shorter_way_across_zero() {
+        let heading = Radians::try_new(TAU - 0.25).unwrap();
+        let target = Radians::try_new(0.5).unwrap();
+        assert_nearly_eq!(0.75, heading.signed_difference(target));
+        assert_nearly_eq!(-0.75, target.signed_difference(heading));
+    }
+
+    #[test]
+    fn signed_difference_of_opposite_headings_is_pi() {
+        let heading = Radians::try_new(0.0).unwrap();
+        let target = Radians::try_new(PI).unwrap();
+        assert_nearly_eq!(PI, heading.signed_difference(target));
+    }
+
+    #[test]
+    fn interpolate_returns_end_headings() {
+        let start = Radians::try_new(1.0).unwrap();
+        let end = Radians::try_new(5.0).unwrap();
+        assert_eq!(start, start.interpolate(end, 0.0));
+        assert_nearly_eq!(end.value(), start.interpolate(end, 1.0).value());
+    }
+
+    #[test]
+    fn interpolate_takes_shorter_way_across_zero() {
+        let start = Radians::try_new(0.5).unwrap();
+        let end = Radians::try_new(TAU - 0.5).unwrap();
+        assert_nearly_eq!(TAU - 0.25, start.interpolate(end, 0.75).value());
+    }
+
+    #[test]
+    fn displays_error_for_non_finite_value() {
+        assert_eq!(
+            "Given value is NaN or infinite",
+            RadiansError::NonFinite.to_string()
+        );
+    }
+
+    #[test]
+    fn serializes_and_deserializes_valid_value() {
+        let radians = Radians::try_new(1.5).unwrap();
+        assert_tokens(
+            &radians,
+            &[
+                Token::Struct {
+                    name: "Radians",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::F64(1.5),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn deserialization_errors_for_out_of_range_value() {
+        assert_de_tokens_error::<Radians>(
+            &[
+                Token::Struct {
+                    name: "Radians",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::F64(12.0),
+                Token::StructEnd,
+            ],
+            "Given value is not in range [0.0; 2π)",
+        );
+    }
 }
@@ -0,0 +1,287 @@
+use crate::{Point, Vector};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+/// A half-line starting at an `origin` and extending infinitely along a `direction`
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │   Origin → ·───────────────→ Direction
+/// │
+/// y
+/// ```
+///
+/// Deserialization goes through [`Ray::try_new`],
+/// so invalid rays are rejected.
+///
+/// [`Ray::try_new`]: ./struct.Ray.html#method.try_new
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedRay")]
+pub struct Ray {
+    /// The point at which the ray starts
+    pub origin: Point,
+    /// The direction in which the ray extends.
+    /// Times of impact are measured in multiples of this vector.
+    pub direction: Vector,
+}
+
+/// Mirrors the serialized representation of a [`Ray`]
+/// before it has been validated
+#[derive(Deserialize)]
+#[serde(rename = "Ray")]
+struct UncheckedRay {
+    origin: Point,
+    direction: Vector,
+}
+
+impl TryFrom<UncheckedRay> for Ray {
+    type Error = RayError;
+
+    fn try_from(unchecked: UncheckedRay) -> Result<Self, Self::Error> {
+        Ray::try_new(unchecked.origin, unchecked.direction)
+    }
+}
+
+impl Ray {
+    /// Creates a new [`Ray`] starting at `origin`.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Ray, Vector};
+    ///
+    /// let ray = Ray::try_new((0.0, 0.0), Vector { x: 1.0, y: 0.0 }).expect("Invalid ray");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`RayError`] if any coordinate is `NaN` or infinite,
+    /// or if the direction is the zero vector.
+    ///
+    /// [`Ray`]: ./struct.Ray.html
+    /// [`RayError`]: ./enum.RayError.html
+    pub fn try_new<P>(origin: P, direction: Vector) -> Result<Self, RayError>
+    where
+        P: Into<Point>,
+    {
+        let origin = origin.into();
+
+        if !origin.x.is_finite()
+            || !origin.y.is_finite()
+            || !direction.x.is_finite()
+            || !direction.y.is_finite()
+        {
+            Err(RayError::NonFiniteCoordinate)
+        } else if direction == Vector::default() {
+            Err(RayError::ZeroDirection)
+        } else {
+            Ok(Self { origin, direction })
+        }
+    }
+
+    /// Returns the point reached after travelling along the ray
+    /// for `time_of_impact` multiples of its direction
+    pub fn point_at(&self, time_of_impact: f64) -> Point {
+        self.origin + Point::from(self.direction * time_of_impact)
+    }
+}
+
+/// The first location at which a [`Ray`] hits a shape
+///
+/// [`Ray`]: ./struct.Ray.html
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct RayHit {
+    /// How many multiples of the ray's direction the ray travelled
+    /// before hitting the shape. This equals the travelled distance
+    /// if the direction is a unit vector.
+    pub time_of_impact: f64,
+    /// The point at which the ray hit the shape
+    pub point: Point,
+    /// The unit normal of the surface that was hit
+    pub normal: Vector,
+}
+
+impl RayHit {
+    /// Creates a hit at `time_of_impact` along `ray`, honoring `max_distance`
+    pub(crate) fn try_new(
+        ray: &Ray,
+        time_of_impact: f64,
+        normal: Vector,
+        max_distance: Option<f64>,
+    ) -> Option<Self> {
+        let exceeds_max_distance = max_distance
+            .map(|max_distance| time_of_impact * ray.direction.magnitude() > max_distance)
+            .unwrap_or(false);
+
+        if time_of_impact < 0.0 || exceeds_max_distance {
+            None
+        } else {
+            Some(Self {
+                time_of_impact,
+                point: ray.point_at(time_of_impact),
+                normal,
+            })
+        }
+    }
+
+    /// Creates a hit for a ray starting inside a shape
+    pub(crate) fn at_origin(ray: &Ray) -> Self {
+        Self {
+            time_of_impact: 0.0,
+            point: ray.origin,
+            normal: ray.direction.unit().negative(),
+        }
+    }
+}
+
+/// Casts [`Ray`]s against a shape
+///
+/// Shapes are treated as solid: A ray starting inside a shape hits it
+/// immediately, at a time of impact of zero and with a normal
+/// pointing against the ray's direction.
+///
+/// [`Ray`]: ./struct.Ray.html
+pub trait RayCast {
+    /// Returns where the `ray` first hits this shape, if at all.
+    ///
+    /// Hits that are further than `max_distance` away from the ray's origin
+    /// are ignored. The distance is measured in the units of the coordinates,
+    /// regardless of the length of the ray's direction.
+    fn cast_ray(&self, ray: &Ray, max_distance: Option<f64>) -> Option<RayHit>;
+}
+
+/// The reason why a [`Ray`] instance could not be created
+///
+/// [`Ray`]: ./struct.Ray.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RayError {
+    /// The origin or direction contained a coordinate that was `NaN` or infinite
+    NonFiniteCoordinate,
+    /// The direction was the zero vector
+    ZeroDirection,
+}
+
+impl fmt::Display for RayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RayError::NonFiniteCoordinate => {
+                write!(f, "Ray has a coordinate that is NaN or infinite")
+            }
+            RayError::ZeroDirection => write!(f, "Ray direction must not be the zero vector"),
+        }
+    }
+}
+
+impl Error for RayError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    fn ray_tokens(origin: (f64, f64), direction: (f64, f64)) -> Vec<Token> {
+        vec![
+            Token::Struct {
+                name: "Ray",
+                len: 2,
+            },
+            Token::Str("origin"),
+            Token::Struct {
+                name: "Point",
+                len: 2,
+            },
+            Token::Str("x"),
+            Token::F64(origin.0),
+            Token::Str("y"),
+            Token::F64(origin.1),
+            Token::StructEnd,
+            Token::Str("direction"),
+            Token::Struct {
+                name: "Vector",
+                len: 2,
+            },
+            Token::Str("x"),
+            Token::F64(direction.0),
+            Token::Str("y"),
+            Token::F64(direction.1),
+            Token::StructEnd,
+            Token::StructEnd,
+        ]
+    }
+
+    #[test]
+    fn try_new_errors_for_zero_direction() {
+        assert_eq!(
+            Err(RayError::ZeroDirection),
+            Ray::try_new((1.0, 1.0), Vector::default())
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_nan_origin() {
+        assert_eq!(
+            Err(RayError::NonFiniteCoordinate),
+            Ray::try_new((f64::NAN, 1.0), Vector { x: 1.0, y: 0.0 })
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_infinite_direction() {
+        assert_eq!(
+            Err(RayError::NonFiniteCoordinate),
+            Ray::try_new(
+                (0.0, 1.0),
+                Vector {
+                    x: f64::INFINITY,
+                    y: 0.0
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn point_at_scales_direction() {
+        let ray = Ray::try_new((1.0, 2.0), Vector { x: 2.0, y: -1.0 }).unwrap();
+        assert_eq!(Point { x: 7.0, y: -1.0 }, ray.point_at(3.0));
+    }
+
+    #[test]
+    fn hit_beyond_max_distance_is_ignored() {
+        let ray = Ray::try_new((0.0, 0.0), Vector { x: 1.0, y: 0.0 }).unwrap();
+        let normal = Vector { x: -1.0, y: 0.0 };
+        assert_eq!(None, RayHit::try_new(&ray, 5.0, normal, Some(4.0)));
+        assert!(RayHit::try_new(&ray, 5.0, normal, Some(5.0)).is_some());
+        assert!(RayHit::try_new(&ray, 5.0, normal, None).is_some());
+    }
+
+    #[test]
+    fn max_distance_does_not_depend_on_direction_length() {
+        let ray = Ray::try_new((0.0, 0.0), Vector { x: 0.0, y: 2.0 }).unwrap();
+        let normal = Vector { x: 0.0, y: -1.0 };
+        assert_eq!(None, RayHit::try_new(&ray, 3.0, normal, Some(5.0)));
+        assert!(RayHit::try_new(&ray, 3.0, normal, Some(6.0)).is_some());
+    }
+
+    #[test]
+    fn serializes_and_deserializes_valid_ray() {
+        let ray = Ray::try_new((1.0, 2.0), Vector { x: 0.0, y: -1.0 }).unwrap();
+        assert_tokens(&ray, &ray_tokens((1.0, 2.0), (0.0, -1.0)));
+    }
+
+    #[test]
+    fn deserialization_errors_for_zero_direction() {
+        assert_de_tokens_error::<Ray>(
+            &ray_tokens((1.0, 2.0), (0.0, 0.0)),
+            &RayError::ZeroDirection.to_string(),
+        );
+    }
+
+    #[test]
+    fn deserialization_errors_for_non_finite_direction() {
+        assert_de_tokens_error::<Ray>(
+            &ray_tokens((1.0, 2.0), (f64::INFINITY, 0.0)),
+            &RayError::NonFiniteCoordinate.to_string(),
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! Rotations that are applied without recomputing their sine and cosine
+
+use crate::{Point, Radians, Vector};
+use std::ops::Mul;
+
+/// A rotation stored as the unit complex number (cos, sin) of its angle.
+///
+/// Converting [`Radians`] into a [`Rotation`] computes the sine and cosine once,
+/// after which the rotation can be applied to any number of points, vectors or polygons,
+/// e.g. through [`Polygon::rotate_around_point`], by multiplications only.
+/// See [`Radians`] for the direction of the rotation.
+///
+/// Rotations are composed by multiplying them. Composing many rotations lets rounding errors
+/// accumulate, which can be undone with [`Rotation::renormalize`].
+///
+/// # Examples
+/// ```
+/// use myelin_geometry::{PolygonBuilder, Point, Radians, Rotation};
+/// use nearly_eq::assert_nearly_eq;
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// let quarter_turn = Rotation::from(Radians::try_new(FRAC_PI_2).unwrap());
+/// let half_turn = quarter_turn * quarter_turn;
+///
+/// let polygon = PolygonBuilder::default()
+///     .vertex(1.0, 0.0)
+///     .vertex(2.0, 0.0)
+///     .vertex(2.0, 1.0)
+///     .build()
+///     .unwrap();
+/// let rotated_polygon = polygon.rotate_around_point(half_turn, Point::default());
+///
+/// assert_nearly_eq!(-1.0, rotated_polygon.vertices()[0].x);
+/// assert_nearly_eq!(0.0, rotated_polygon.vertices()[0].y);
+/// ```
+///
+/// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+/// [`Rotation`]: ./struct.Rotation.html
+/// [`Polygon::rotate_around_point`]: ./struct.Polygon.html#method.rotate_around_point
+/// [`Rotation::renormalize`]: ./struct.Rotation.html#method.renormalize
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Rotation {
+    cos: f64,
+    sin: f64,
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<Radians> for Rotation {
+    fn from(radians: Radians) -> Self {
+        Self::from_angle(radians.value())
+    }
+}
+
+impl Mul for Rotation {
+    type Output = Rotation;
+
+    /// Composes two rotations, i.e. adds their angles
+    fn mul(self, other: Rotation) -> Self::Output {
+        Rotation {
+            cos: self.cos * other.cos - self.sin * other.sin,
+            sin: self.sin * other.cos + self.cos * other.sin,
+        }
+    }
+}
+
+impl Rotation {
+    /// Creates a [`Rotation`] that leaves everything in place
+    ///
+    /// [`Rotation`]: ./struct.Rotation.html
+    pub fn identity() -> Self {
+        Self { cos: 1.0, sin: 0.0 }
+    }
+
+    /// Returns the cosine of the rotation's angle
+    pub fn cos(self) -> f64 {
+        self.cos
+    }
+
+    /// Returns the sine of the rotation's angle
+    pub fn sin(self) -> f64 {
+        self.sin
+    }
+
+    /// Returns the angle of the rotation
+    pub fn angle(self) -> Radians {
+        // Safe unwrap: The sine and cosine are finite, as slerp rejects non-finite angles,
+        // and their arctangent is thus finite as well
+        Radians::try_new_normalized(self.signed_angle()).unwrap()
+    }
+
+    /// Returns the rotation that undoes this rotation
+    pub fn inverse(self) -> Rotation {
+        Rotation {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Scales the stored complex number back to a magnitude of 1,
+    /// removing the drift that composing many rotations causes.
+    pub fn renormalize(self) -> Rotation {
+        let magnitude = self.cos.hypot(self.sin);
+        if magnitude == 0.0 {
+            return Rotation::identity();
+        }
+
+        Rotation {
+            cos: self.cos / magnitude,
+            sin: self.sin / magnitude,
+        }
+    }
+
+    /// Spherically interpolates between this rotation and `other`, turning
+    /// the shorter way around at a constant angular speed.
+    /// A `factor` of 0 returns this rotation, a `factor` of 1 returns `other`.
+    ///
+    /// # Panics
+    /// Panics if `factor` is `NaN` or infinite,
+    /// or so large that the interpolated angle overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Radians, Rotation};
+    /// use nearly_eq::assert_nearly_eq;
+    ///
+    /// let start = Rotation::from(Radians::try_from_degrees(350.0).unwrap());
+    /// let end = Rotation::from(Radians::try_from_degrees(30.0).unwrap());
+    ///
+    /// let halfway = start.slerp(end, 0.5);
+    /// assert_nearly_eq!(Radians::try_from_degrees(10.0).unwrap().value(), halfway.angle().value());
+    /// ```
+    pub fn slerp(self, other: Rotation, factor: f64) -> Rotation {
+        let angle = (self.inverse() * other).signed_angle() * factor;
+        assert!(
+            angle.is_finite(),
+            "Interpolated between rotations with a non-finite factor"
+        );
+        self * Rotation::from_angle(angle)
+    }
+
+    /// Rotates a vector around the origin
+    pub(crate) fn rotate_vector(self, vector: Vector) -> Vector {
+        // See https://en.wikipedia.org/wiki/Rotation_matrix
+        Vector {
+            x: self.cos * vector.x + self.sin * vector.y,
+            y: -self.sin * vector.x + self.cos * vector.y,
+        }
+    }
+
+    /// Rotates a point around `center`
+    pub(crate) fn rotate_point_around_point(self, point: Point, center: Point) -> Point {
+        let delta = Vector::from(point - center);
+        center + Point::from(self.rotate_vector(delta))
+    }
+
+    fn from_angle(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { cos, sin }
+    }
+
+    /// Returns the angle of the rotation in the range (-π; π]
+    fn signed_angle(self) -> f64 {
+        self.sin.atan2(self.cos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PolygonBuilder;
+    use nearly_eq::assert_nearly_eq;
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    fn rotation(angle: f64) -> Rotation {
+        Rotation::from(Radians::try_new(angle).unwrap())
+    }
+
+    #[test]
+    fn is_created_from_radians() {
+        let rotation = rotation(FRAC_PI_2);
+
+        assert_nearly_eq!(0.0, rotation.cos());
+        assert_nearly_eq!(1.0, rotation.sin());
+        assert_nearly_eq!(FRAC_PI_2, rotation.angle().value());
+    }
+
+    #[test]
+    fn multiplication_adds_angles() {
+        let composed = rotation(2.0) * rotation(3.0);
+
+        assert_nearly_eq!(5.0, composed.angle().value());
+    }
+
+    #[test]
+    fn multiplication_wraps_around() {
+        let composed = rotation(4.0) * rotation(3.0);
+
+        assert_nearly_eq!(7.0 - TAU, composed.angle().value());
+    }
+
+    #[test]
+    fn inverse_undoes_rotation() {
+        let identity = rotation(1.234) * rotation(1.234).inverse();
+
+        assert_nearly_eq!(1.0, identity.cos());
+        assert_nearly_eq!(0.0, identity.sin());
+    }
+
+    #[test]
+    fn renormalize_removes_drift() {
+        let step = Rotation {
+            cos: 0.8 * 1.001,
+            sin: 0.6 * 1.001,
+        };
+        let drifted = (0..100).fold(Rotation::identity(), |rotation, _| rotation * step);
+
+        let renormalized = drifted.renormalize();
+
+        assert!(drifted.cos().hypot(drifted.sin()) > 1.1);
+        assert_nearly_eq!(1.0, renormalized.cos().hypot(renormalized.sin()));
+        assert_nearly_eq!(drifted.angle().value(), renormalized.angle().value());
+    }
+
+    #[test]
+    fn slerp_returns_end_rotations() {
+        let start = rotation(1.0);
+        let end = rotation(5.5);
+
+        assert_eq!(start, start.slerp(end, 0.0));
+        assert_nearly_eq!(end.angle().value(), start.slerp(end, 1.0).angle().value());
+    }
+
+    #[test]
+    fn slerp_turns_the_shorter_way() {
+        let quarter = rotation(0.5).slerp(rotation(TAU - 0.5), 0.25);
+
+        assert_nearly_eq!(0.25, quarter.angle().value());
+    }
+
+    #[test]
+    fn slerp_keeps_unit_magnitude() {
+        let halfway = rotation(0.0).slerp(rotation(PI - 0.1), 0.5);
+
+        assert_nearly_eq!(1.0, halfway.cos().hypot(halfway.sin()));
+        assert_nearly_eq!((PI - 0.1) / 2.0, halfway.angle().value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn slerp_panics_with_nan_factor() {
+        rotation(1.0).slerp(rotation(2.0), f64::NAN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slerp_panics_when_angle_overflows() {
+        rotation(1.0).slerp(rotation(3.0), f64::MAX);
+    }
+
+    #[test]
+    fn rotates_polygon_like_radians() {
+        let polygon = PolygonBuilder::default()
+            .vertex(0.0, 0.0)
+            .vertex(3.0, 1.0)
+            .vertex(-1.0, 4.0)
+            .build()
+            .unwrap();
+        let center = Point { x: 2.0, y: -1.0 };
+
+        let expected = polygon.rotate_around_point(Radians::try_new(2.5).unwrap(), center);
+        let rotated = polygon.rotate_around_point(rotation(2.5), center);
+
+        for (&expected, &actual) in expected.vertices().iter().zip(rotated.vertices()) {
+            assert_nearly_eq!(expected.x, actual.x);
+            assert_nearly_eq!(expected.y, actual.y);
+        }
+    }
+}
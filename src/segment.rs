@@ -0,0 +1,414 @@
+use crate::{Aabb, Intersects, Point, Polygon, Vector};
+use serde::{Deserialize, Serialize};
+
+/// Relative tolerance under which two segments are considered parallel
+/// or a point is considered to lie on a segment's line
+const EPSILON: f64 = 0.000_000_001;
+
+/// A line segment between two points
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │   Start → ·
+/// │            ╲
+/// │             ╲
+/// │              · ← End
+/// │
+/// y
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Segment {
+    /// The point at which the segment starts
+    pub start: Point,
+    /// The point at which the segment ends
+    pub end: Point,
+}
+
+/// The result of intersecting two [`Segment`]s
+///
+/// [`Segment`]: ./struct.Segment.html
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum SegmentIntersection {
+    /// The segments cross or touch in a single point
+    Point(Point),
+    /// The segments are collinear and share the given sub-segment
+    Overlap(Segment),
+}
+
+impl Segment {
+    /// Creates a new [`Segment`] from `start` to `end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::Segment;
+    ///
+    /// let segment = Segment::new((0.0, 0.0), (10.0, 5.0));
+    /// ```
+    ///
+    /// [`Segment`]: ./struct.Segment.html
+    pub fn new<P1, P2>(start: P1, end: P2) -> Self
+    where
+        P1: Into<Point>,
+        P2: Into<Point>,
+    {
+        Self {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+
+    /// Returns the vector pointing from `start` to `end`
+    pub fn direction(&self) -> Vector {
+        Vector::from(self.end - self.start)
+    }
+
+    /// Returns the distance between `start` and `end`
+    pub fn length(&self) -> f64 {
+        self.direction().magnitude()
+    }
+
+    /// Returns the point halfway between `start` and `end`
+    pub fn midpoint(&self) -> Point {
+        self.start + Point::from(self.direction() / 2.0)
+    }
+
+    /// Returns the point on the segment that is closest to `point`
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, Segment};
+    ///
+    /// let segment = Segment::new((0.0, 0.0), (10.0, 0.0));
+    /// assert_eq!(
+    ///     Point { x: 4.0, y: 0.0 },
+    ///     segment.closest_point(Point { x: 4.0, y: 3.0 })
+    /// );
+    /// assert_eq!(
+    ///     Point { x: 10.0, y: 0.0 },
+    ///     segment.closest_point(Point { x: 12.0, y: 3.0 })
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Point) -> Point {
+        let direction = self.direction();
+        let squared_length = direction.dot_product(direction);
+        if squared_length == 0.0 {
+            return self.start;
+        }
+
+        let projection = Vector::from(point - self.start).dot_product(direction) / squared_length;
+        self.point_at(projection.clamp(0.0, 1.0))
+    }
+
+    /// Calculates where this segment and `other` meet.
+    ///
+    /// Returns `None` if the segments don't touch, a single point if they cross or touch,
+    /// and the shared sub-segment if they are collinear and overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, Segment, SegmentIntersection};
+    ///
+    /// let first_segment = Segment::new((0.0, 0.0), (10.0, 10.0));
+    /// let second_segment = Segment::new((0.0, 10.0), (10.0, 0.0));
+    /// assert_eq!(
+    ///     Some(SegmentIntersection::Point(Point { x: 5.0, y: 5.0 })),
+    ///     first_segment.intersection(&second_segment)
+    /// );
+    /// ```
+    pub fn intersection(&self, other: &Segment) -> Option<SegmentIntersection> {
+        // See https://stackoverflow.com/a/565282
+        let own_direction = self.direction();
+        let other_direction = other.direction();
+        let start_to_other_start = Vector::from(other.start - self.start);
+
+        let denominator = own_direction.cross_product(other_direction);
+        let parallel_tolerance = EPSILON * own_direction.magnitude() * other_direction.magnitude();
+
+        if denominator.abs() > parallel_tolerance {
+            let own_factor = start_to_other_start.cross_product(other_direction) / denominator;
+            let other_factor = start_to_other_start.cross_product(own_direction) / denominator;
+            return if (0.0..=1.0).contains(&own_factor) && (0.0..=1.0).contains(&other_factor) {
+                Some(SegmentIntersection::Point(self.point_at(own_factor)))
+            } else {
+                None
+            };
+        }
+
+        if own_direction == Vector::default() {
+            return if other.contains_point(self.start) {
+                Some(SegmentIntersection::Point(self.start))
+            } else {
+                None
+            };
+        }
+
+        if !self.line_contains_point(other.start) {
+            return None;
+        }
+
+        // The segments are collinear, so we can describe the other segment
+        // in terms of this segment's parametrization
+        let squared_length = own_direction.dot_product(own_direction);
+        let other_start_factor = start_to_other_start.dot_product(own_direction) / squared_length;
+        let other_end_factor =
+            Vector::from(other.end - self.start).dot_product(own_direction) / squared_length;
+
+        let overlap_start = other_start_factor.min(other_end_factor).max(0.0);
+        let overlap_end = other_start_factor.max(other_end_factor).min(1.0);
+
+        if overlap_start > overlap_end {
+            None
+        } else if overlap_start == overlap_end {
+            Some(SegmentIntersection::Point(self.point_at(overlap_start)))
+        } else {
+            Some(SegmentIntersection::Overlap(Segment {
+                start: self.point_at(overlap_start),
+                end: self.point_at(overlap_end),
+            }))
+        }
+    }
+
+    /// Checks if a given point lies on the segment
+    pub fn contains_point(&self, point: Point) -> bool {
+        let closest_point = self.closest_point(point);
+        let distance = Vector::from(point - closest_point).magnitude();
+        distance <= EPSILON * self.length().max(1.0)
+    }
+
+    fn line_contains_point(&self, point: Point) -> bool {
+        let direction = self.direction();
+        let start_to_point = Vector::from(point - self.start);
+        direction.cross_product(start_to_point).abs()
+            <= EPSILON * direction.magnitude() * start_to_point.magnitude()
+    }
+
+    fn point_at(&self, factor: f64) -> Point {
+        self.start + Point::from(self.direction() * factor)
+    }
+}
+
+impl Intersects for Segment {
+    /// Returns wether this segment touches or overlaps another segment
+    fn intersects(&self, other: &Segment) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+impl Intersects<Polygon> for Segment {
+    /// Returns wether this segment touches, crosses or is contained in a [`Polygon`]
+    ///
+    /// [`Polygon`]: ./struct.Polygon.html
+    fn intersects(&self, polygon: &Polygon) -> bool {
+        polygon.contains_point(self.start)
+            || polygon
+                .counter_clockwise_segments()
+                .any(|edge| self.intersects(&edge))
+    }
+}
+
+impl Intersects<Segment> for Polygon {
+    /// Returns wether this polygon touches, crosses or contains a [`Segment`]
+    ///
+    /// [`Segment`]: ./struct.Segment.html
+    fn intersects(&self, segment: &Segment) -> bool {
+        segment.intersects(self)
+    }
+}
+
+impl Intersects<Aabb> for Segment {
+    /// Returns wether this segment touches, crosses or is contained in an [`Aabb`]
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    fn intersects(&self, aabb: &Aabb) -> bool {
+        self.intersects(&Polygon::from(*aabb))
+    }
+}
+
+impl Intersects<Segment> for Aabb {
+    /// Returns wether this [`Aabb`] touches, crosses or contains a segment
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    fn intersects(&self, segment: &Segment) -> bool {
+        segment.intersects(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PolygonBuilder;
+    use nearly_eq::assert_nearly_eq;
+
+    fn triangle() -> Polygon {
+        PolygonBuilder::default()
+            .vertex(0.0, 0.0)
+            .vertex(10.0, 0.0)
+            .vertex(0.0, 10.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn length_is_correct() {
+        let segment = Segment::new((1.0, 1.0), (4.0, 5.0));
+        assert_nearly_eq!(5.0, segment.length());
+    }
+
+    #[test]
+    fn length_of_degenerate_segment_is_zero() {
+        let segment = Segment::new((1.0, 1.0), (1.0, 1.0));
+        assert_nearly_eq!(0.0, segment.length());
+    }
+
+    #[test]
+    fn midpoint_is_correct() {
+        let segment = Segment::new((-2.0, 1.0), (4.0, 5.0));
+        assert_eq!(Point { x: 1.0, y: 3.0 }, segment.midpoint());
+    }
+
+    #[test]
+    fn closest_point_before_start_is_start() {
+        let segment = Segment::new((0.0, 0.0), (10.0, 0.0));
+        assert_eq!(
+            Point { x: 0.0, y: 0.0 },
+            segment.closest_point(Point { x: -5.0, y: 5.0 })
+        );
+    }
+
+    #[test]
+    fn closest_point_on_degenerate_segment_is_start() {
+        let segment = Segment::new((3.0, 3.0), (3.0, 3.0));
+        assert_eq!(
+            Point { x: 3.0, y: 3.0 },
+            segment.closest_point(Point { x: -5.0, y: 5.0 })
+        );
+    }
+
+    #[test]
+    fn crossing_segments_intersect_in_point() {
+        let first_segment = Segment::new((0.0, 0.0), (4.0, 0.0));
+        let second_segment = Segment::new((1.0, -1.0), (1.0, 1.0));
+        assert_eq!(
+            Some(SegmentIntersection::Point(Point { x: 1.0, y: 0.0 })),
+            first_segment.intersection(&second_segment)
+        );
+        assert_eq!(
+            Some(SegmentIntersection::Point(Point { x: 1.0, y: 0.0 })),
+            second_segment.intersection(&first_segment)
+        );
+    }
+
+    #[test]
+    fn segments_touching_at_endpoints_intersect_in_point() {
+        let first_segment = Segment::new((0.0, 0.0), (4.0, 0.0));
+        let second_segment = Segment::new((4.0, 0.0), (5.0, 3.0));
+        assert_eq!(
+            Some(SegmentIntersection::Point(Point { x: 4.0, y: 0.0 })),
+            first_segment.intersection(&second_segment)
+        );
+    }
+
+    #[test]
+    fn non_crossing_segments_do_not_intersect() {
+        let first_segment = Segment::new((0.0, 0.0), (4.0, 0.0));
+        let second_segment = Segment::new((5.0, -1.0), (5.0, 1.0));
+        assert_eq!(None, first_segment.intersection(&second_segment));
+        assert!(!first_segment.intersects(&second_segment));
+    }
+
+    #[test]
+    fn parallel_segments_do_not_intersect() {
+        let first_segment = Segment::new((0.0, 0.0), (4.0, 0.0));
+        let second_segment = Segment::new((0.0, 1.0), (4.0, 1.0));
+        assert_eq!(None, first_segment.intersection(&second_segment));
+    }
+
+    #[test]
+    fn collinear_segments_intersect_in_overlap() {
+        let first_segment = Segment::new((0.0, 0.0), (4.0, 4.0));
+        let second_segment = Segment::new((6.0, 6.0), (2.0, 2.0));
+        assert_eq!(
+            Some(SegmentIntersection::Overlap(Segment::new(
+                (2.0, 2.0),
+                (4.0, 4.0)
+            ))),
+            first_segment.intersection(&second_segment)
+        );
+    }
+
+    #[test]
+    fn collinear_segments_touching_at_endpoints_intersect_in_point() {
+        let first_segment = Segment::new((0.0, 0.0), (4.0, 0.0));
+        let second_segment = Segment::new((4.0, 0.0), (8.0, 0.0));
+        assert_eq!(
+            Some(SegmentIntersection::Point(Point { x: 4.0, y: 0.0 })),
+            first_segment.intersection(&second_segment)
+        );
+    }
+
+    #[test]
+    fn disjoint_collinear_segments_do_not_intersect() {
+        let first_segment = Segment::new((0.0, 0.0), (4.0, 0.0));
+        let second_segment = Segment::new((5.0, 0.0), (8.0, 0.0));
+        assert_eq!(None, first_segment.intersection(&second_segment));
+    }
+
+    #[test]
+    fn degenerate_segment_intersects_segment_it_lies_on() {
+        let first_segment = Segment::new((2.0, 0.0), (2.0, 0.0));
+        let second_segment = Segment::new((0.0, 0.0), (4.0, 0.0));
+        assert_eq!(
+            Some(SegmentIntersection::Point(Point { x: 2.0, y: 0.0 })),
+            first_segment.intersection(&second_segment)
+        );
+        assert_eq!(
+            Some(SegmentIntersection::Point(Point { x: 2.0, y: 0.0 })),
+            second_segment.intersection(&first_segment)
+        );
+    }
+
+    #[test]
+    fn intersects_polygon_when_crossing() {
+        let segment = Segment::new((-5.0, 5.0), (5.0, 5.0));
+        assert!(segment.intersects(&triangle()));
+        assert!(triangle().intersects(&segment));
+    }
+
+    #[test]
+    fn intersects_polygon_when_contained() {
+        let segment = Segment::new((1.0, 1.0), (2.0, 2.0));
+        assert!(segment.intersects(&triangle()));
+        assert!(triangle().intersects(&segment));
+    }
+
+    #[test]
+    fn does_not_intersect_polygon_when_apart() {
+        let segment = Segment::new((6.0, 6.0), (10.0, 10.0));
+        assert!(!segment.intersects(&triangle()));
+        assert!(!triangle().intersects(&segment));
+    }
+
+    #[test]
+    fn intersects_aabb_when_crossing() {
+        let aabb = Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap();
+        let segment = Segment::new((-5.0, 15.0), (15.0, -5.0));
+        assert!(segment.intersects(&aabb));
+        assert!(aabb.intersects(&segment));
+    }
+
+    #[test]
+    fn intersects_aabb_when_contained() {
+        let aabb = Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap();
+        let segment = Segment::new((2.0, 3.0), (4.0, 5.0));
+        assert!(segment.intersects(&aabb));
+        assert!(aabb.intersects(&segment));
+    }
+
+    #[test]
+    fn does_not_intersect_aabb_when_passing_corner() {
+        let aabb = Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap();
+        let segment = Segment::new((11.0, 0.0), (20.0, 9.0));
+        assert!(!segment.intersects(&aabb));
+        assert!(!aabb.intersects(&segment));
+    }
+}
@@ -0,0 +1,580 @@
+//! Types relating to 2D simple polygons, which may be concave
+
+use crate::polygon::bounding_box;
+use crate::{Aabb, Point, Polygon, PolygonError, Rotation, Segment, SegmentIntersection, Vector};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+/// A simple polygon, i.e. a closed ring of vertices whose edges
+/// don't intersect each other. Unlike a [`Polygon`], it may be concave.
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │   ┌─────────────┐
+/// │   │             │
+/// │   │    ┌────────┘
+/// │   │    │
+/// │   └────┘
+/// y
+/// ```
+///
+/// Deserialization goes through [`SimplePolygon::try_new`],
+/// so invalid vertices are rejected.
+///
+/// [`Polygon`]: ./struct.Polygon.html
+/// [`SimplePolygon::try_new`]: ./struct.SimplePolygon.html#method.try_new
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedSimplePolygon")]
+pub struct SimplePolygon {
+    /// The vertices of the polygon
+    vertices: Vec<Point>,
+}
+
+/// Mirrors the serialized representation of a [`SimplePolygon`]
+/// before its vertices have been validated
+#[derive(Deserialize)]
+#[serde(rename = "SimplePolygon")]
+struct UncheckedSimplePolygon {
+    vertices: Vec<Point>,
+}
+
+impl TryFrom<UncheckedSimplePolygon> for SimplePolygon {
+    type Error = SimplePolygonError;
+
+    fn try_from(unchecked: UncheckedSimplePolygon) -> Result<Self, Self::Error> {
+        SimplePolygon::try_new(unchecked.vertices)
+    }
+}
+
+/// The direction in which the vertices of a polygon are ordered
+///
+/// Like every orientation name in this crate, e.g. of [`ConvexHull`] or [`Radians`],
+/// the winding uses the mathematical convention in which y points up:
+/// counter clockwise vertices turn from the positive x axis towards the positive y axis
+/// and enclose a positive signed area.
+/// In the diagrams of this crate, which draw y pointing down, they appear clockwise on screen.
+///
+/// [`ConvexHull`]: ./struct.ConvexHull.html
+/// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Winding {
+    /// The vertices are ordered counter clockwise
+    CounterClockwise,
+    /// The vertices are ordered clockwise
+    Clockwise,
+}
+
+impl SimplePolygon {
+    /// Creates a new [`SimplePolygon`] from the given [`Point`]s.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Point, SimplePolygon};
+    ///
+    /// let l_shape = SimplePolygon::try_new(vec![
+    ///     Point { x: 0.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 0.0 },
+    ///     Point { x: 10.0, y: 5.0 },
+    ///     Point { x: 5.0, y: 5.0 },
+    ///     Point { x: 5.0, y: 10.0 },
+    ///     Point { x: 0.0, y: 10.0 },
+    /// ])
+    /// .expect("Polygon is not simple");
+    /// assert_eq!(75.0, l_shape.area());
+    /// ```
+    ///
+    /// # Errors
+    /// This method will return a [`SimplePolygonError`] if fewer than three vertices
+    /// are given, if any vertex is `NaN` or infinite, if a vertex is repeated,
+    /// or if any two edges intersect each other.
+    ///
+    /// [`SimplePolygon`]: ./struct.SimplePolygon.html
+    /// [`Point`]: ./struct.Point.html
+    /// [`SimplePolygonError`]: ./enum.SimplePolygonError.html
+    pub fn try_new(vertices: Vec<Point>) -> Result<Self, SimplePolygonError> {
+        const MINIMUM_VERTICES_IN_EUCLIDEAN_GEOMETRY: usize = 3;
+
+        if vertices.len() < MINIMUM_VERTICES_IN_EUCLIDEAN_GEOMETRY {
+            return Err(SimplePolygonError::TooFewVertices {
+                count: vertices.len(),
+            });
+        }
+
+        if let Some(index) = vertices
+            .iter()
+            .position(|vertex| !vertex.x.is_finite() || !vertex.y.is_finite())
+        {
+            return Err(SimplePolygonError::NonFiniteVertex { index });
+        }
+
+        if let Some(index) =
+            (1..vertices.len()).find(|&index| vertices[..index].contains(&vertices[index]))
+        {
+            return Err(SimplePolygonError::DuplicateVertex { index });
+        }
+
+        if let Some((first_edge, second_edge)) = find_intersecting_edges(&vertices) {
+            return Err(SimplePolygonError::SelfIntersecting {
+                first_edge,
+                second_edge,
+            });
+        }
+
+        Ok(Self { vertices })
+    }
+
+    /// Creates a new [`SimplePolygon`] without validating the vertices.
+    /// Used for results that are simple by construction.
+    ///
+    /// [`SimplePolygon`]: ./struct.SimplePolygon.html
+    pub(crate) fn new_unchecked(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    /// Returns the vertices of the polygon
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Returns the polygon's edges as positioned [`Segment`]s.
+    /// The `n`th segment starts at the `n`th vertex.
+    ///
+    /// [`Segment`]: ./struct.Segment.html
+    pub fn segments(&self) -> impl Iterator<Item = Segment> + '_ {
+        ring_segments(&self.vertices)
+    }
+
+    /// Returns the area enclosed by the polygon
+    pub fn area(&self) -> f64 {
+        signed_area(&self.vertices).abs()
+    }
+
+    /// Returns the direction in which the vertices are ordered
+    pub fn winding(&self) -> Winding {
+        if signed_area(&self.vertices) > 0.0 {
+            Winding::CounterClockwise
+        } else {
+            Winding::Clockwise
+        }
+    }
+
+    /// Checks if a given point rests inside the polygon or on its boundary.
+    /// Uses the [winding number] of the polygon around the point.
+    ///
+    /// [winding number]: https://en.wikipedia.org/wiki/Point_in_polygon#Winding_number_algorithm
+    pub fn contains_point(&self, point: Point) -> bool {
+        ring_contains_point(&self.vertices, point)
+    }
+
+    /// Returns an [`Aabb`] which fully contains this polygon.
+    ///
+    /// [`Aabb`]: ./struct.Aabb.html
+    pub fn aabb(&self) -> Aabb {
+        bounding_box(&self.vertices)
+    }
+
+    /// Apply translation specified by `translation`, represented as
+    /// a relative point
+    pub fn translate(&self, translation: Point) -> Self {
+        Self {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|&vertex| vertex + translation)
+                .collect(),
+        }
+    }
+
+    /// Rotate polygon by a `rotation` around a `point`.
+    /// See [`Radians`] for the direction of the rotation.
+    ///
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    pub fn rotate_around_point<R>(&self, rotation: R, point: Point) -> Self
+    where
+        R: Into<Rotation>,
+    {
+        let rotation = rotation.into();
+        Self {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|&vertex| vertex.rotate_around_point(rotation, point))
+                .collect(),
+        }
+    }
+
+    /// Returns wether the polygon is convex and can thus be converted into a [`Polygon`]
+    ///
+    /// [`Polygon`]: ./struct.Polygon.html
+    pub fn is_convex(&self) -> bool {
+        Polygon::try_new(self.vertices.clone()).is_ok()
+    }
+}
+
+impl TryFrom<SimplePolygon> for Polygon {
+    type Error = PolygonError;
+
+    /// Converts a [`SimplePolygon`] into a convex [`Polygon`].
+    ///
+    /// # Errors
+    /// Returns a [`PolygonError`] if the polygon is not convex.
+    ///
+    /// [`SimplePolygon`]: ./struct.SimplePolygon.html
+    /// [`Polygon`]: ./struct.Polygon.html
+    /// [`PolygonError`]: ./enum.PolygonError.html
+    fn try_from(simple_polygon: SimplePolygon) -> Result<Self, Self::Error> {
+        Polygon::try_new(simple_polygon.vertices)
+    }
+}
+
+impl From<Polygon> for SimplePolygon {
+    fn from(polygon: Polygon) -> Self {
+        Self {
+            vertices: polygon.counter_clockwise_vertices(),
+        }
+    }
+}
+
+/// Iterates over the edges of a closed ring of vertices
+pub(crate) fn ring_segments(vertices: &[Point]) -> impl Iterator<Item = Segment> + '_ {
+    let shifted_vertices = vertices.iter().cycle().skip(1).take(vertices.len());
+    vertices
+        .iter()
+        .zip(shifted_vertices)
+        .map(|(&start, &end)| Segment { start, end })
+}
+
+/// Calculates the area enclosed by a closed ring of vertices using the
+/// [shoelace formula]. The area is positive if the vertices are ordered
+/// counter clockwise and negative otherwise.
+///
+/// [shoelace formula]: https://en.wikipedia.org/wiki/Shoelace_formula
+pub(crate) fn signed_area(vertices: &[Point]) -> f64 {
+    ring_segments(vertices)
+        .map(|segment| Vector::from(segment.start).cross_product(Vector::from(segment.end)))
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Checks if a point rests inside or on the boundary of a closed ring of vertices
+pub(crate) fn ring_contains_point(vertices: &[Point], point: Point) -> bool {
+    if ring_segments(vertices).any(|segment| segment.contains_point(point)) {
+        return true;
+    }
+
+    // See http://geomalgorithms.com/a03-_inclusion.html
+    let winding_number: i32 = ring_segments(vertices)
+        .map(|segment| {
+            let is_left = segment
+                .direction()
+                .cross_product(Vector::from(point - segment.start));
+            if segment.start.y <= point.y && segment.end.y > point.y && is_left > 0.0 {
+                1
+            } else if segment.start.y > point.y && segment.end.y <= point.y && is_left < 0.0 {
+                -1
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    winding_number != 0
+}
+
+/// Returns the indices of the first two edges of the ring that intersect
+/// anywhere except at the vertex that connects them
+fn find_intersecting_edges(vertices: &[Point]) -> Option<(usize, usize)> {
+    let segments: Vec<_> = ring_segments(vertices).collect();
+    let edge_count = segments.len();
+
+    (0..edge_count)
+        .flat_map(|first_edge| {
+            (first_edge + 1..edge_count).map(move |second_edge| (first_edge, second_edge))
+        })
+        .find(|&(first_edge, second_edge)| {
+            let intersection = segments[first_edge].intersection(&segments[second_edge]);
+            let shared_vertex = if second_edge == first_edge + 1 {
+                Some(segments[first_edge].end)
+            } else if first_edge == 0 && second_edge == edge_count - 1 {
+                Some(segments[first_edge].start)
+            } else {
+                None
+            };
+
+            match (intersection, shared_vertex) {
+                (None, _) => false,
+                (Some(SegmentIntersection::Point(point)), Some(shared_vertex)) => {
+                    point != shared_vertex
+                }
+                _ => true,
+            }
+        })
+}
+
+/// The reason why a [`SimplePolygon`] could not be created
+///
+/// [`SimplePolygon`]: ./struct.SimplePolygon.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SimplePolygonError {
+    /// Fewer than three vertices were given
+    TooFewVertices {
+        /// The number of vertices that were given
+        count: usize,
+    },
+    /// A vertex contained a coordinate that was `NaN` or infinite
+    NonFiniteVertex {
+        /// The index of the offending vertex
+        index: usize,
+    },
+    /// A vertex was given more than once
+    DuplicateVertex {
+        /// The index of the repeated vertex
+        index: usize,
+    },
+    /// Two edges intersect each other.
+    /// The `n`th edge starts at the `n`th vertex.
+    SelfIntersecting {
+        /// The index of the first intersecting edge
+        first_edge: usize,
+        /// The index of the second intersecting edge
+        second_edge: usize,
+    },
+}
+
+impl fmt::Display for SimplePolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimplePolygonError::TooFewVertices { count } => write!(
+                f,
+                "A polygon requires at least three vertices, but {} were given",
+                count
+            ),
+            SimplePolygonError::NonFiniteVertex { index } => write!(
+                f,
+                "Vertex at index {} has a coordinate that is NaN or infinite",
+                index
+            ),
+            SimplePolygonError::DuplicateVertex { index } => {
+                write!(f, "Vertex at index {} was given more than once", index)
+            }
+            SimplePolygonError::SelfIntersecting {
+                first_edge,
+                second_edge,
+            } => write!(
+                f,
+                "Polygon is not simple: edges {} and {} intersect",
+                first_edge, second_edge
+            ),
+        }
+    }
+}
+
+impl Error for SimplePolygonError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Radians;
+    use nearly_eq::assert_nearly_eq;
+    use std::f64::consts::PI;
+
+    fn l_shape() -> SimplePolygon {
+        SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 5.0 },
+            Point { x: 5.0, y: 5.0 },
+            Point { x: 5.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn try_new_errors_for_two_vertices() {
+        assert_eq!(
+            Err(SimplePolygonError::TooFewVertices { count: 2 }),
+            SimplePolygon::try_new(vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 0.0 }])
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_non_finite_vertex() {
+        assert_eq!(
+            Err(SimplePolygonError::NonFiniteVertex { index: 1 }),
+            SimplePolygon::try_new(vec![
+                Point { x: 0.0, y: 0.0 },
+                Point {
+                    x: f64::NEG_INFINITY,
+                    y: 0.0
+                },
+                Point { x: 0.0, y: 1.0 },
+            ])
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_duplicate_vertex() {
+        assert_eq!(
+            Err(SimplePolygonError::DuplicateVertex { index: 3 }),
+            SimplePolygon::try_new(vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 2.0, y: 0.0 },
+                Point { x: 1.0, y: 1.0 },
+                Point { x: 2.0, y: 0.0 },
+                Point { x: 2.0, y: 2.0 },
+            ])
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_bowtie() {
+        assert_eq!(
+            Err(SimplePolygonError::SelfIntersecting {
+                first_edge: 1,
+                second_edge: 3
+            }),
+            SimplePolygon::try_new(vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 0.0, y: 1.0 },
+                Point { x: 1.0, y: 0.0 },
+                Point { x: 1.0, y: 1.0 },
+            ])
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_collinear_vertices() {
+        assert!(SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 2.0, y: 0.0 },
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn try_new_accepts_collinear_vertex_on_edge() {
+        assert!(SimplePolygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 2.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+        ])
+        .is_ok());
+    }
+
+    #[test]
+    fn area_is_correct_for_concave_polygon() {
+        assert_nearly_eq!(75.0, l_shape().area());
+    }
+
+    #[test]
+    fn winding_is_counter_clockwise_for_positive_area() {
+        assert_eq!(Winding::CounterClockwise, l_shape().winding());
+    }
+
+    #[test]
+    fn winding_is_clockwise_for_reversed_vertices() {
+        let mut vertices = l_shape().vertices().to_vec();
+        vertices.reverse();
+        let polygon = SimplePolygon::try_new(vertices).unwrap();
+        assert_eq!(Winding::Clockwise, polygon.winding());
+        assert_nearly_eq!(75.0, polygon.area());
+    }
+
+    #[test]
+    fn contains_point_inside() {
+        assert!(l_shape().contains_point(Point { x: 2.0, y: 8.0 }));
+        assert!(l_shape().contains_point(Point { x: 8.0, y: 2.0 }));
+    }
+
+    #[test]
+    fn contains_point_on_boundary() {
+        assert!(l_shape().contains_point(Point { x: 7.0, y: 5.0 }));
+        assert!(l_shape().contains_point(Point { x: 5.0, y: 5.0 }));
+    }
+
+    #[test]
+    fn does_not_contain_point_in_notch() {
+        assert!(!l_shape().contains_point(Point { x: 8.0, y: 8.0 }));
+    }
+
+    #[test]
+    fn does_not_contain_point_in_notch_when_clockwise() {
+        let mut vertices = l_shape().vertices().to_vec();
+        vertices.reverse();
+        let polygon = SimplePolygon::try_new(vertices).unwrap();
+        assert!(!polygon.contains_point(Point { x: 8.0, y: 8.0 }));
+        assert!(polygon.contains_point(Point { x: 2.0, y: 8.0 }));
+    }
+
+    #[test]
+    fn aabb_is_correct() {
+        let expected_aabb = Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap();
+        assert_eq!(expected_aabb, l_shape().aabb());
+    }
+
+    #[test]
+    fn translates() {
+        let translated_polygon = l_shape().translate(Point { x: 1.0, y: -1.0 });
+        assert_eq!(Point { x: 1.0, y: -1.0 }, translated_polygon.vertices()[0]);
+        assert_eq!(Point { x: 1.0, y: 9.0 }, translated_polygon.vertices()[5]);
+    }
+
+    #[test]
+    fn rotates_like_polygon() {
+        let triangle = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+        ];
+        let rotation = Radians::try_new(PI / 3.0).unwrap();
+        let center = Point { x: 1.0, y: 2.0 };
+
+        let rotated_simple_polygon = SimplePolygon::try_new(triangle.clone())
+            .unwrap()
+            .rotate_around_point(rotation, center);
+        let rotated_polygon = Polygon::try_new(triangle)
+            .unwrap()
+            .rotate_around_point(rotation, center);
+
+        assert_eq!(
+            rotated_polygon.vertices(),
+            rotated_simple_polygon.vertices()
+        );
+    }
+
+    #[test]
+    fn concave_polygon_is_not_convex() {
+        assert!(!l_shape().is_convex());
+        assert!(Polygon::try_from(l_shape()).is_err());
+    }
+
+    #[test]
+    fn convex_polygon_can_be_converted() {
+        let vertices = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+        ];
+        let simple_polygon = SimplePolygon::try_new(vertices.clone()).unwrap();
+
+        assert!(simple_polygon.is_convex());
+        assert_eq!(
+            Polygon::try_new(vertices).unwrap(),
+            Polygon::try_from(simple_polygon).unwrap()
+        );
+    }
+
+    #[test]
+    fn polygon_created_from_aabb_is_simple() {
+        let aabb = Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap();
+        let simple_polygon = SimplePolygon::from(Polygon::from(aabb));
+        assert!(SimplePolygon::try_new(simple_polygon.vertices().to_vec()).is_ok());
+        assert_nearly_eq!(100.0, simple_polygon.area());
+    }
+}
@@ -0,0 +1,432 @@
+//! Affine transformations of geometry types
+
+use crate::{Point, Rotation, Vector};
+use std::error::Error;
+use std::fmt;
+
+/// A 2D [affine transformation], i.e. a linear map followed by a translation.
+///
+/// Transforms are built from translations, rotations, scales and reflections,
+/// and combined with [`Transform::then`]. They are applied to geometry types
+/// through the [`Transformable`] trait.
+///
+/// Scaling by zero is rejected, so a transform can usually be undone with [`Transform::try_inverse`].
+/// Composing extreme scales can still make it irreversible due to rounding.
+///
+/// # Examples
+/// ```
+/// use myelin_geometry::{Point, Transform, Transformable, Vector};
+///
+/// let transform = Transform::try_scale(2.0)
+///     .unwrap()
+///     .then(Transform::translation(Vector { x: 10.0, y: 0.0 }));
+///
+/// let point = Point { x: 1.0, y: 1.0 }.transform(transform);
+/// assert_eq!(Point { x: 12.0, y: 2.0 }, point);
+/// assert_eq!(Point { x: 1.0, y: 1.0 }, point.transform(transform.try_inverse().unwrap()));
+/// ```
+///
+/// [affine transformation]: https://en.wikipedia.org/wiki/Affine_transformation
+/// [`Transform::then`]: ./struct.Transform.html#method.then
+/// [`Transformable`]: ./trait.Transformable.html
+/// [`Transform::try_inverse`]: ./struct.Transform.html#method.try_inverse
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Transform {
+    /// The rows of the matrix describing the linear part of the transform
+    matrix: [[f64; 2]; 2],
+    /// The translation applied after the linear part
+    translation: Vector,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform {
+    /// Creates a [`Transform`] that leaves everything as it is
+    ///
+    /// [`Transform`]: ./struct.Transform.html
+    pub fn identity() -> Self {
+        Self {
+            matrix: [[1.0, 0.0], [0.0, 1.0]],
+            translation: Vector::default(),
+        }
+    }
+
+    /// Creates a [`Transform`] that moves points by `translation`.
+    /// Vectors are not affected by translations.
+    ///
+    /// [`Transform`]: ./struct.Transform.html
+    pub fn translation(translation: Vector) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a [`Transform`] that rotates around the origin.
+    /// See [`Radians`] for the direction of the rotation.
+    ///
+    /// [`Transform`]: ./struct.Transform.html
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    pub fn rotation<R>(rotation: R) -> Self
+    where
+        R: Into<Rotation>,
+    {
+        let rotation = rotation.into();
+        let (sin, cos) = (rotation.sin(), rotation.cos());
+        Self {
+            matrix: [[cos, sin], [-sin, cos]],
+            translation: Vector::default(),
+        }
+    }
+
+    /// Creates a [`Transform`] that rotates around `point`.
+    /// See [`Radians`] for the direction of the rotation.
+    ///
+    /// [`Transform`]: ./struct.Transform.html
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    pub fn rotation_around_point<R>(rotation: R, point: Point) -> Self
+    where
+        R: Into<Rotation>,
+    {
+        Self::translation(Vector::from(point).negative())
+            .then(Self::rotation(rotation))
+            .then(Self::translation(Vector::from(point)))
+    }
+
+    /// Creates a [`Transform`] that scales uniformly, with the origin as the fixed point.
+    ///
+    /// # Errors
+    /// Returns a [`TransformError`] if `factor` is zero, `NaN` or infinite.
+    ///
+    /// [`Transform`]: ./struct.Transform.html
+    /// [`TransformError`]: ./enum.TransformError.html
+    pub fn try_scale(factor: f64) -> Result<Self, TransformError> {
+        Self::try_non_uniform_scale(factor, factor)
+    }
+
+    /// Creates a [`Transform`] that scales the x and y axes separately,
+    /// with the origin as the fixed point. Negative factors mirror along the respective axis.
+    ///
+    /// # Errors
+    /// Returns a [`TransformError`] if either factor is zero, `NaN` or infinite.
+    ///
+    /// [`Transform`]: ./struct.Transform.html
+    /// [`TransformError`]: ./enum.TransformError.html
+    pub fn try_non_uniform_scale(x_factor: f64, y_factor: f64) -> Result<Self, TransformError> {
+        if !x_factor.is_finite() || !y_factor.is_finite() {
+            Err(TransformError::NonFiniteScale)
+        } else if x_factor == 0.0 || y_factor == 0.0 {
+            Err(TransformError::ZeroScale)
+        } else {
+            Ok(Self {
+                matrix: [[x_factor, 0.0], [0.0, y_factor]],
+                translation: Vector::default(),
+            })
+        }
+    }
+
+    /// Creates a [`Transform`] that mirrors across the line through the origin along `axis`.
+    ///
+    /// # Errors
+    /// Returns [`TransformError::InvalidReflectionAxis`] if `axis` is the zero vector
+    /// or contains a coordinate that is `NaN` or infinite.
+    ///
+    /// [`Transform`]: ./struct.Transform.html
+    /// [`TransformError::InvalidReflectionAxis`]: ./enum.TransformError.html#variant.InvalidReflectionAxis
+    pub fn try_reflection(axis: Vector) -> Result<Self, TransformError> {
+        let is_valid = axis.x.is_finite() && axis.y.is_finite() && axis != Vector::default();
+        if !is_valid {
+            return Err(TransformError::InvalidReflectionAxis);
+        }
+
+        // See https://en.wikipedia.org/wiki/Reflection_(mathematics)#Reflection_across_a_line_in_the_plane
+        let Vector { x, y } = axis.unit();
+        Ok(Self {
+            matrix: [[x * x - y * y, 2.0 * x * y], [2.0 * x * y, y * y - x * x]],
+            translation: Vector::default(),
+        })
+    }
+
+    /// Combines this transform with `next`, so that the result
+    /// first applies this transform and then `next`
+    pub fn then(self, next: Transform) -> Transform {
+        let [[a, b], [c, d]] = next.matrix;
+        let [[e, f], [g, h]] = self.matrix;
+        Self {
+            matrix: [
+                [a * e + b * g, a * f + b * h],
+                [c * e + d * g, c * f + d * h],
+            ],
+            translation: next.map_vector(self.translation) + next.translation,
+        }
+    }
+
+    /// Returns the transform that undoes this transform
+    ///
+    /// # Errors
+    /// Returns [`TransformError::NotInvertible`] if rounding made the transform irreversible,
+    /// e.g. after composing scales so small that its determinant underflows to zero,
+    /// or if the inverse can't be represented with finite numbers.
+    ///
+    /// [`TransformError::NotInvertible`]: ./enum.TransformError.html#variant.NotInvertible
+    pub fn try_inverse(self) -> Result<Transform, TransformError> {
+        let [[a, b], [c, d]] = self.matrix;
+        let determinant = a * d - b * c;
+        if determinant == 0.0 || !determinant.is_finite() {
+            return Err(TransformError::NotInvertible);
+        }
+
+        let inverse_linear_part = Self {
+            matrix: [
+                [d / determinant, -b / determinant],
+                [-c / determinant, a / determinant],
+            ],
+            translation: Vector::default(),
+        };
+        let inverse = Self {
+            translation: inverse_linear_part.map_vector(self.translation).negative(),
+            ..inverse_linear_part
+        };
+
+        let [[e, f], [g, h]] = inverse.matrix;
+        let is_finite = [e, f, g, h, inverse.translation.x, inverse.translation.y]
+            .iter()
+            .all(|value| value.is_finite());
+        if is_finite {
+            Ok(inverse)
+        } else {
+            Err(TransformError::NotInvertible)
+        }
+    }
+
+    /// Applies the transform to a point
+    pub(crate) fn map_point(self, point: Point) -> Point {
+        Point::from(self.map_vector(Vector::from(point)) + self.translation)
+    }
+
+    /// Applies the linear part of the transform to a vector
+    pub(crate) fn map_vector(self, vector: Vector) -> Vector {
+        let [[a, b], [c, d]] = self.matrix;
+        Vector {
+            x: a * vector.x + b * vector.y,
+            y: c * vector.x + d * vector.y,
+        }
+    }
+}
+
+/// A geometry type that can be moved, rotated, scaled or mirrored by a [`Transform`]
+///
+/// The results are not validated again. Transforms that overflow produce `NaN` or
+/// infinite coordinates, and scales that are tiny compared to the coordinates can
+/// round distinct points onto each other, both of which the validating
+/// constructors of the transformed types would reject.
+///
+/// [`Transform`]: ./struct.Transform.html
+pub trait Transformable {
+    /// Returns a copy of this value with `transform` applied to it
+    fn transform(&self, transform: Transform) -> Self;
+}
+
+/// The reason why a [`Transform`] instance could not be created
+///
+/// [`Transform`]: ./struct.Transform.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum TransformError {
+    /// A scale factor was `NaN` or infinite
+    NonFiniteScale,
+    /// A scale factor was zero, which would make the transform irreversible
+    ZeroScale,
+    /// The axis of a reflection was the zero vector or not finite
+    InvalidReflectionAxis,
+    /// The transform could not be inverted, as rounding made it lose a dimension
+    /// or its inverse is too large to represent
+    NotInvertible,
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransformError::NonFiniteScale => write!(f, "Scale factor is NaN or infinite"),
+            TransformError::ZeroScale => write!(f, "Scale factor must not be zero"),
+            TransformError::InvalidReflectionAxis => {
+                write!(f, "Reflection axis must be a finite, non-zero vector")
+            }
+            TransformError::NotInvertible => write!(f, "Transform is not invertible"),
+        }
+    }
+}
+
+impl Error for TransformError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aabb, Polygon, PolygonBuilder, Radians};
+    use nearly_eq::assert_nearly_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn assert_points_nearly_eq(expected: Point, actual: Point) {
+        assert_nearly_eq!(expected.x, actual.x);
+        assert_nearly_eq!(expected.y, actual.y);
+    }
+
+    fn triangle() -> Polygon {
+        PolygonBuilder::default()
+            .vertex(0.0, 0.0)
+            .vertex(4.0, 0.0)
+            .vertex(0.0, 2.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn then_applies_transforms_in_order() {
+        let scale_then_translate = Transform::try_scale(3.0)
+            .unwrap()
+            .then(Transform::translation(Vector { x: 1.0, y: 0.0 }));
+        let translate_then_scale = Transform::translation(Vector { x: 1.0, y: 0.0 })
+            .then(Transform::try_scale(3.0).unwrap());
+
+        let point = Point { x: 1.0, y: 2.0 };
+
+        assert_eq!(
+            Point { x: 4.0, y: 6.0 },
+            point.transform(scale_then_translate)
+        );
+        assert_eq!(
+            Point { x: 6.0, y: 6.0 },
+            point.transform(translate_then_scale)
+        );
+    }
+
+    #[test]
+    fn inverse_undoes_transform() {
+        let transform = Transform::try_non_uniform_scale(2.0, -0.5)
+            .unwrap()
+            .then(Transform::rotation(Radians::try_new(1.0).unwrap()))
+            .then(Transform::translation(Vector { x: -3.0, y: 7.0 }));
+        let point = Point { x: 5.0, y: -2.0 };
+
+        assert_points_nearly_eq(
+            point,
+            point
+                .transform(transform)
+                .transform(transform.try_inverse().unwrap()),
+        );
+        assert_points_nearly_eq(
+            point,
+            point
+                .transform(transform.try_inverse().unwrap())
+                .transform(transform),
+        );
+    }
+
+    #[test]
+    fn try_inverse_errors_when_determinant_underflows() {
+        let tiny_scale = Transform::try_scale(1e-200).unwrap();
+
+        assert_eq!(
+            Err(TransformError::NotInvertible),
+            tiny_scale.then(tiny_scale).try_inverse()
+        );
+    }
+
+    #[test]
+    fn try_inverse_errors_when_inverse_overflows() {
+        let transform = Transform::try_non_uniform_scale(1e-300, 1e300)
+            .unwrap()
+            .then(Transform::translation(Vector { x: 1e10, y: 0.0 }));
+
+        assert_eq!(Err(TransformError::NotInvertible), transform.try_inverse());
+    }
+
+    #[test]
+    fn rotation_matches_rotate_around_point() {
+        let rotation = Radians::try_new(FRAC_PI_2).unwrap();
+        let center = Point { x: 1.0, y: 1.0 };
+
+        let transformed = triangle().transform(Transform::rotation_around_point(rotation, center));
+        let rotated = triangle().rotate_around_point(rotation, center);
+
+        for (&expected, &actual) in rotated.vertices().iter().zip(transformed.vertices()) {
+            assert_points_nearly_eq(expected, actual);
+        }
+    }
+
+    #[test]
+    fn reflection_mirrors_across_axis() {
+        let reflection = Transform::try_reflection(Vector { x: 1.0, y: 1.0 }).unwrap();
+
+        assert_points_nearly_eq(
+            Point { x: 2.0, y: 5.0 },
+            Point { x: 5.0, y: 2.0 }.transform(reflection),
+        );
+        assert_points_nearly_eq(
+            Point { x: 3.0, y: 3.0 },
+            Point { x: 3.0, y: 3.0 }.transform(reflection),
+        );
+    }
+
+    #[test]
+    fn vectors_ignore_translation() {
+        let transform = Transform::translation(Vector { x: 10.0, y: 10.0 })
+            .then(Transform::try_scale(2.0).unwrap());
+
+        assert_eq!(
+            Vector { x: 2.0, y: -4.0 },
+            Vector { x: 1.0, y: -2.0 }.transform(transform)
+        );
+    }
+
+    #[test]
+    fn mirrored_polygon_stays_valid() {
+        let mirror = Transform::try_non_uniform_scale(-1.0, 1.0).unwrap();
+
+        let mirrored = triangle().transform(mirror);
+
+        assert!(Polygon::try_new(mirrored.vertices().to_vec()).is_ok());
+        assert!(mirrored.contains_point(Point { x: -1.0, y: 0.5 }));
+    }
+
+    #[test]
+    fn aabb_encloses_transformed_corners() {
+        let aabb = Aabb::try_new((0.0, 0.0), (2.0, 2.0)).unwrap();
+        let transform = Transform::rotation_around_point(
+            Radians::try_new(FRAC_PI_2 / 2.0).unwrap(),
+            Point { x: 1.0, y: 1.0 },
+        );
+
+        let transformed = aabb.transform(transform);
+
+        let half_diagonal = 2.0f64.sqrt();
+        assert_nearly_eq!(1.0 - half_diagonal, transformed.upper_left.x);
+        assert_nearly_eq!(1.0 - half_diagonal, transformed.upper_left.y);
+        assert_nearly_eq!(1.0 + half_diagonal, transformed.lower_right.x);
+        assert_nearly_eq!(1.0 + half_diagonal, transformed.lower_right.y);
+    }
+
+    #[test]
+    fn try_scale_errors_for_zero() {
+        assert_eq!(Err(TransformError::ZeroScale), Transform::try_scale(0.0));
+    }
+
+    #[test]
+    fn try_non_uniform_scale_errors_for_nan() {
+        assert_eq!(
+            Err(TransformError::NonFiniteScale),
+            Transform::try_non_uniform_scale(1.0, f64::NAN)
+        );
+    }
+
+    #[test]
+    fn try_reflection_errors_for_zero_axis() {
+        assert_eq!(
+            Err(TransformError::InvalidReflectionAxis),
+            Transform::try_reflection(Vector::default())
+        );
+    }
+}
@@ -0,0 +1,499 @@
+//! Triangulation of polygons by ear clipping
+
+use crate::simple_polygon::signed_area;
+use crate::{Point, Polygon, PolygonWithHoles, SimplePolygon, Vector};
+use std::cmp::Ordering;
+
+/// Triangulates a simple ring of vertices, optionally with `holes` cut out of it,
+/// by repeatedly clipping [ears].
+///
+/// The triangles are returned as counter clockwise triples of indices into the
+/// vertices of `exterior` followed by the vertices of each hole, in order.
+/// Neither the exterior nor the holes need to follow a specific winding.
+/// Collinear and repeated vertices don't form triangles of their own,
+/// so no degenerate triangles are emitted for them.
+///
+/// The rings are not validated: If they intersect themselves or each other,
+/// the result covers an unspecified area.
+///
+/// # Examples
+/// ```
+/// use myelin_geometry::{triangulate, Point};
+///
+/// let l_shape = [
+///     Point { x: 0.0, y: 0.0 },
+///     Point { x: 10.0, y: 0.0 },
+///     Point { x: 10.0, y: 5.0 },
+///     Point { x: 5.0, y: 5.0 },
+///     Point { x: 5.0, y: 10.0 },
+///     Point { x: 0.0, y: 10.0 },
+/// ];
+/// let no_holes: [&[Point]; 0] = [];
+///
+/// let triangles = triangulate(&l_shape, &no_holes);
+/// assert_eq!(4, triangles.len());
+/// ```
+///
+/// [ears]: https://en.wikipedia.org/wiki/Polygon_triangulation#Ear_clipping_method
+pub fn triangulate<H>(exterior: &[Point], holes: &[H]) -> Vec<[usize; 3]>
+where
+    H: AsRef<[Point]>,
+{
+    let mut vertices = exterior.to_vec();
+    let mut hole_rings = Vec::with_capacity(holes.len());
+    for hole in holes {
+        let hole = hole.as_ref();
+        let offset = vertices.len();
+        vertices.extend_from_slice(hole);
+        hole_rings.push(oriented_ring(
+            &vertices,
+            (offset..vertices.len()).collect(),
+            false,
+        ));
+    }
+
+    let mut ring = oriented_ring(&vertices, (0..exterior.len()).collect(), true);
+
+    // Holes are bridged from right to left, so that earlier bridges can't
+    // block the view of holes that are bridged later
+    hole_rings.retain(|hole_ring| !hole_ring.is_empty());
+    hole_rings.sort_by(|first, second| {
+        let first_x = vertices[first[rightmost_position(&vertices, first)]].x;
+        let second_x = vertices[second[rightmost_position(&vertices, second)]].x;
+        second_x.partial_cmp(&first_x).unwrap_or(Ordering::Equal)
+    });
+    for hole_ring in hole_rings {
+        ring = bridge_hole(&vertices, ring, &hole_ring);
+    }
+
+    clip_ears(&vertices, ring)
+}
+
+impl Polygon {
+    /// Triangulates the polygon.
+    ///
+    /// The triangles are returned as counter clockwise triples of indices
+    /// into [`Polygon::vertices`].
+    ///
+    /// [`Polygon::vertices`]: ./struct.Polygon.html#method.vertices
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        let indices = self.counter_clockwise_vertex_indices();
+        let ordered_vertices: Vec<_> = indices
+            .iter()
+            .map(|&index| self.vertices()[index])
+            .collect();
+        ear_clip(&ordered_vertices)
+            .into_iter()
+            .map(|triangle| {
+                [
+                    indices[triangle[0]],
+                    indices[triangle[1]],
+                    indices[triangle[2]],
+                ]
+            })
+            .collect()
+    }
+}
+
+impl SimplePolygon {
+    /// Triangulates the polygon.
+    ///
+    /// The triangles are returned as counter clockwise triples of indices
+    /// into [`SimplePolygon::vertices`].
+    ///
+    /// [`SimplePolygon::vertices`]: ./struct.SimplePolygon.html#method.vertices
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        ear_clip(self.vertices())
+    }
+}
+
+impl PolygonWithHoles {
+    /// Triangulates the area between the exterior and the holes.
+    ///
+    /// The triangles are returned as counter clockwise triples of indices
+    /// into [`PolygonWithHoles::vertices`].
+    ///
+    /// [`PolygonWithHoles::vertices`]: ./struct.PolygonWithHoles.html#method.vertices
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        let holes: Vec<_> = self.holes().iter().map(SimplePolygon::vertices).collect();
+        triangulate(self.exterior().vertices(), &holes)
+    }
+}
+
+/// Triangulates a simple ring of vertices without holes
+pub(crate) fn ear_clip(vertices: &[Point]) -> Vec<[usize; 3]> {
+    let ring = oriented_ring(vertices, (0..vertices.len()).collect(), true);
+    clip_ears(vertices, ring)
+}
+
+/// Orders a ring of vertex indices counter clockwise or clockwise
+fn oriented_ring(vertices: &[Point], mut ring: Vec<usize>, counter_clockwise: bool) -> Vec<usize> {
+    let ring_vertices: Vec<_> = ring.iter().map(|&index| vertices[index]).collect();
+    let is_counter_clockwise = signed_area(&ring_vertices) >= 0.0;
+    if is_counter_clockwise != counter_clockwise {
+        ring.reverse();
+    }
+    ring
+}
+
+fn rightmost_position(vertices: &[Point], ring: &[usize]) -> usize {
+    (0..ring.len())
+        .max_by(|&first, &second| {
+            let first = vertices[ring[first]];
+            let second = vertices[ring[second]];
+            first
+                .x
+                .partial_cmp(&second.x)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| second.y.partial_cmp(&first.y).unwrap_or(Ordering::Equal))
+        })
+        .unwrap_or_default()
+}
+
+/// Joins a clockwise hole into a counter clockwise ring by cutting a bridge
+/// from the hole's rightmost vertex to a visible vertex of the ring.
+///
+/// See [Triangulation by Ear Clipping] by David Eberly.
+///
+/// [Triangulation by Ear Clipping]: https://www.geometrictools.com/Documentation/TriangulationByEarClipping.pdf
+fn bridge_hole(vertices: &[Point], ring: Vec<usize>, hole_ring: &[usize]) -> Vec<usize> {
+    let hole_position = rightmost_position(vertices, hole_ring);
+    let hole_vertex = vertices[hole_ring[hole_position]];
+
+    let bridge_position = match find_bridge_position(vertices, &ring, hole_vertex) {
+        Some(bridge_position) => bridge_position,
+        None => return ring,
+    };
+
+    let hole_len = hole_ring.len();
+    let hole_loop = (0..=hole_len).map(|offset| hole_ring[(hole_position + offset) % hole_len]);
+
+    ring[..=bridge_position]
+        .iter()
+        .copied()
+        .chain(hole_loop)
+        .chain(ring[bridge_position..].iter().copied())
+        .collect()
+}
+
+/// Finds the position of a ring vertex that can be connected to `hole_vertex`
+/// without crossing any edge of the ring
+fn find_bridge_position(vertices: &[Point], ring: &[usize], hole_vertex: Point) -> Option<usize> {
+    // Cast a ray from the hole vertex to the right and find the closest edge it hits
+    let (edge_position, intersection_x) = (0..ring.len())
+        .filter_map(|position| {
+            let start = vertices[ring[position]];
+            let end = vertices[ring[(position + 1) % ring.len()]];
+            let crosses_ray = start.y.min(end.y) <= hole_vertex.y
+                && hole_vertex.y <= start.y.max(end.y)
+                && start.y != end.y;
+            if !crosses_ray {
+                return None;
+            }
+            let intersection_x =
+                start.x + (hole_vertex.y - start.y) * (end.x - start.x) / (end.y - start.y);
+            if intersection_x >= hole_vertex.x {
+                Some((position, intersection_x))
+            } else {
+                None
+            }
+        })
+        .min_by(|(_, first), (_, second)| first.partial_cmp(second).unwrap_or(Ordering::Equal))?;
+
+    let start_position = edge_position;
+    let end_position = (edge_position + 1) % ring.len();
+    let intersection = Point {
+        x: intersection_x,
+        y: hole_vertex.y,
+    };
+    if vertices[ring[start_position]] == intersection {
+        return Some(start_position);
+    }
+    if vertices[ring[end_position]] == intersection {
+        return Some(end_position);
+    }
+
+    let candidate_position = if vertices[ring[start_position]].x > vertices[ring[end_position]].x {
+        start_position
+    } else {
+        end_position
+    };
+    let candidate = vertices[ring[candidate_position]];
+
+    // Reflex vertices inside the triangle spanned by the hole vertex, the intersection
+    // and the candidate could block the view on the candidate. If so, the blocking
+    // vertex closest in angle to the ray is visible instead.
+    let mut triangle = [hole_vertex, intersection, candidate];
+    if signed_area(&triangle) < 0.0 {
+        triangle.swap(1, 2);
+    }
+    let blocking_position = (0..ring.len())
+        .filter(|&position| {
+            let vertex = vertices[ring[position]];
+            turn(vertices, ring, position) < 0.0
+                && !triangle.contains(&vertex)
+                && triangle_contains_point(triangle, vertex)
+                && opens_towards(vertices, ring, position, hole_vertex)
+        })
+        .min_by(|&first, &second| {
+            let angle_to_ray = |position: usize| {
+                let offset = vertices[ring[position]] - hole_vertex;
+                (
+                    offset.y.abs().atan2(offset.x),
+                    Vector::from(offset).magnitude(),
+                )
+            };
+            angle_to_ray(first)
+                .partial_cmp(&angle_to_ray(second))
+                .unwrap_or(Ordering::Equal)
+        });
+
+    Some(blocking_position.unwrap_or(candidate_position))
+}
+
+/// Checks if `point` lies within the interior angle at the vertex at `position`.
+/// Vertices that were duplicated by earlier bridges are only visible
+/// from inside one of their copies' angles.
+fn opens_towards(vertices: &[Point], ring: &[usize], position: usize, point: Point) -> bool {
+    let (previous, current, next) = neighbours(ring, position);
+    let (previous, current, next) = (vertices[previous], vertices[current], vertices[next]);
+    let is_left_of_incoming =
+        Vector::from(current - previous).cross_product(Vector::from(point - previous)) > 0.0;
+    let is_left_of_outgoing =
+        Vector::from(next - current).cross_product(Vector::from(point - current)) > 0.0;
+
+    if turn(vertices, ring, position) > 0.0 {
+        is_left_of_incoming && is_left_of_outgoing
+    } else {
+        is_left_of_incoming || is_left_of_outgoing
+    }
+}
+
+/// Clips ears off a counter clockwise ring of vertex indices until only triangles remain
+fn clip_ears(vertices: &[Point], mut ring: Vec<usize>) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2));
+    while ring.len() >= 3 {
+        if let Some(position) = (0..ring.len()).find(|&position| is_ear(vertices, &ring, position))
+        {
+            let (previous, current, next) = neighbours(&ring, position);
+            triangles.push([previous, current, next]);
+            ring.remove(position);
+        } else if let Some(position) =
+            (0..ring.len()).find(|&position| turn(vertices, &ring, position) == 0.0)
+        {
+            // Collinear and repeated vertices enclose no area
+            ring.remove(position);
+        } else if let Some(position) =
+            (0..ring.len()).find(|&position| turn(vertices, &ring, position) > 0.0)
+        {
+            // Rounding errors or invalid input left no proper ear,
+            // so fall back to clipping any convex vertex
+            let (previous, current, next) = neighbours(&ring, position);
+            triangles.push([previous, current, next]);
+            ring.remove(position);
+        } else {
+            break;
+        }
+    }
+
+    triangles
+}
+
+fn neighbours(ring: &[usize], position: usize) -> (usize, usize, usize) {
+    let previous = ring[(position + ring.len() - 1) % ring.len()];
+    let next = ring[(position + 1) % ring.len()];
+    (previous, ring[position], next)
+}
+
+/// Returns the cross product of the two edges meeting at the vertex at `position`.
+/// Positive values indicate a convex, counter clockwise turn.
+fn turn(vertices: &[Point], ring: &[usize], position: usize) -> f64 {
+    let (previous, current, next) = neighbours(ring, position);
+    Vector::from(vertices[current] - vertices[previous])
+        .cross_product(Vector::from(vertices[next] - vertices[current]))
+}
+
+fn is_ear(vertices: &[Point], ring: &[usize], position: usize) -> bool {
+    if turn(vertices, ring, position) <= 0.0 {
+        return false;
+    }
+
+    let (previous, current, next) = neighbours(ring, position);
+    let triangle = [vertices[previous], vertices[current], vertices[next]];
+    ring.iter()
+        .map(|&index| vertices[index])
+        .filter(|point| !triangle.contains(point))
+        .all(|point| !triangle_contains_point(triangle, point))
+}
+
+/// Checks if `point` lies inside or on the boundary of a counter clockwise triangle
+pub(crate) fn triangle_contains_point(triangle: [Point; 3], point: Point) -> bool {
+    (0..3).all(|index| {
+        let start = triangle[index];
+        let end = triangle[(index + 1) % 3];
+        Vector::from(end - start).cross_product(Vector::from(point - start)) >= 0.0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nearly_eq::assert_nearly_eq;
+
+    const NO_HOLES: [&[Point]; 0] = [];
+
+    fn triangle_area(vertices: &[Point], triangle: [usize; 3]) -> f64 {
+        signed_area(&[
+            vertices[triangle[0]],
+            vertices[triangle[1]],
+            vertices[triangle[2]],
+        ])
+    }
+
+    fn assert_valid_triangulation(vertices: &[Point], triangles: &[[usize; 3]], area: f64) {
+        for &triangle in triangles {
+            assert!(triangle_area(vertices, triangle) > 0.0);
+        }
+        let total_area: f64 = triangles
+            .iter()
+            .map(|&triangle| triangle_area(vertices, triangle))
+            .sum();
+        assert_nearly_eq!(area, total_area, 0.000_001);
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> Vec<Point> {
+        vec![
+            Point { x, y },
+            Point { x: x + size, y },
+            Point {
+                x: x + size,
+                y: y + size,
+            },
+            Point { x, y: y + size },
+        ]
+    }
+
+    #[test]
+    fn triangulates_concave_ring() {
+        let vertices = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 5.0 },
+            Point { x: 5.0, y: 5.0 },
+            Point { x: 5.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+        ];
+
+        let triangles = triangulate(&vertices, &NO_HOLES);
+
+        assert_eq!(4, triangles.len());
+        assert_valid_triangulation(&vertices, &triangles, 75.0);
+    }
+
+    #[test]
+    fn emits_counter_clockwise_triangles_for_clockwise_ring() {
+        let mut vertices = square(0.0, 0.0, 10.0);
+        vertices.reverse();
+
+        let triangles = triangulate(&vertices, &NO_HOLES);
+
+        assert_eq!(2, triangles.len());
+        assert_valid_triangulation(&vertices, &triangles, 100.0);
+    }
+
+    #[test]
+    fn skips_collinear_and_repeated_vertices() {
+        let vertices = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 5.0, y: 0.0 },
+            Point { x: 5.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+            Point { x: 0.0, y: 5.0 },
+        ];
+
+        let triangles = triangulate(&vertices, &NO_HOLES);
+
+        assert_valid_triangulation(&vertices, &triangles, 100.0);
+    }
+
+    #[test]
+    fn triangulates_around_holes() {
+        let exterior = square(0.0, 0.0, 10.0);
+        let holes = vec![square(2.0, 2.0, 2.0), square(6.0, 5.0, 3.0)];
+        let vertices: Vec<_> = exterior
+            .iter()
+            .chain(holes.iter().flatten())
+            .copied()
+            .collect();
+
+        let triangles = triangulate(&exterior, &holes);
+
+        assert_valid_triangulation(&vertices, &triangles, 100.0 - 4.0 - 9.0);
+        let hole_centers = [Point { x: 3.0, y: 3.0 }, Point { x: 7.5, y: 6.5 }];
+        for &triangle in &triangles {
+            let corners = [
+                vertices[triangle[0]],
+                vertices[triangle[1]],
+                vertices[triangle[2]],
+            ];
+            for &hole_center in &hole_centers {
+                assert!(!triangle_contains_point(corners, hole_center));
+            }
+        }
+    }
+
+    #[test]
+    fn triangulates_hole_behind_reflex_vertex() {
+        let exterior = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 20.0, y: 0.0 },
+            Point { x: 20.0, y: 10.0 },
+            Point { x: 12.0, y: 10.0 },
+            Point { x: 12.0, y: 4.0 },
+            Point { x: 10.0, y: 4.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 0.0, y: 10.0 },
+        ];
+        let holes = vec![square(2.0, 5.0, 2.0)];
+        let vertices: Vec<_> = exterior
+            .iter()
+            .chain(holes.iter().flatten())
+            .copied()
+            .collect();
+
+        let triangles = triangulate(&exterior, &holes);
+
+        assert_valid_triangulation(&vertices, &triangles, 200.0 - 12.0 - 4.0);
+    }
+
+    #[test]
+    fn polygon_triangles_index_into_stored_vertices() {
+        let polygon = Polygon::try_new(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 10.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+        ])
+        .unwrap();
+
+        let triangles = polygon.triangulate();
+
+        assert_eq!(2, triangles.len());
+        assert_valid_triangulation(polygon.vertices(), &triangles, 100.0);
+    }
+
+    #[test]
+    fn polygon_with_holes_triangles_index_into_all_vertices() {
+        let exterior = SimplePolygon::try_new(square(0.0, 0.0, 10.0)).unwrap();
+        let hole = SimplePolygon::try_new(square(4.0, 4.0, 2.0)).unwrap();
+        let polygon = PolygonWithHoles::try_new(exterior, vec![hole]).unwrap();
+        let vertices: Vec<_> = polygon.vertices().collect();
+
+        let triangles = polygon.triangulate();
+
+        assert_eq!(8, vertices.len());
+        assert_valid_triangulation(&vertices, &triangles, 96.0);
+    }
+}
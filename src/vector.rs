@@ -1,5 +1,5 @@
 use crate::radians::Radians;
-use crate::Point;
+use crate::{Point, Rotation, Transform, Transformable};
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, Div, Mul, Sub};
 
@@ -128,32 +128,38 @@ impl Vector {
         }
     }
 
-    /// Rotate a vector by the given amount (counterclockwise)
-    pub fn rotate(self, rotation: Radians) -> Self {
-        // Radians are contained in the range [0.0; 2π).
-        // However, the rotation should be applied counterclockwise, so we invert this value.
-        let adjusted_rotation = -rotation.value();
-
-        let (rotation_sin, rotation_cos) = adjusted_rotation.sin_cos();
-        let rotated_x = rotation_cos * self.x + rotation_sin * self.y;
-        let rotated_y = -rotation_sin * self.x + rotation_cos * self.y;
-
-        Vector {
-            x: rotated_x,
-            y: rotated_y,
-        }
-    }
-
-    /// Rotate a vector by the given amount (clockwise)
+    /// Rotate a vector by the given amount, in the same direction as
+    /// points and polygons are rotated. See [`Radians`] for the direction of the rotation.
+    /// Passing a [`Rotation`] instead of [`Radians`] skips computing its sine and cosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Radians, Vector};
+    /// use nearly_eq::assert_nearly_eq;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let vector = Vector { x: 1.0, y: 0.0 };
+    /// let rotated_vector = vector.rotate(Radians::try_new(FRAC_PI_2).unwrap());
+    /// assert_nearly_eq!(0.0, rotated_vector.x);
+    /// assert_nearly_eq!(-1.0, rotated_vector.y);
+    /// ```
+    ///
+    /// [`Radians`]: ./struct.Radians.html#direction-of-rotation
+    /// [`Rotation`]: ./struct.Rotation.html
+    pub fn rotate<R>(self, rotation: R) -> Self
+    where
+        R: Into<Rotation>,
+    {
+        rotation.into().rotate_vector(self)
+    }
+
+    /// Rotate a vector by the given amount.
+    /// This is the same as [`Vector::rotate`].
+    ///
+    /// [`Vector::rotate`]: ./struct.Vector.html#method.rotate
+    #[deprecated(note = "use `Vector::rotate`, which now rotates in the same direction")]
     pub fn rotate_clockwise(self, rotation: Radians) -> Self {
-        let (rotation_sin, rotation_cos) = rotation.value().sin_cos();
-        let rotated_x = rotation_cos * self.x + rotation_sin * self.y;
-        let rotated_y = -rotation_sin * self.x + rotation_cos * self.y;
-
-        Vector {
-            x: rotated_x,
-            y: rotated_y,
-        }
+        self.rotate(rotation)
     }
 
     /// Negates the vector, returning a vector with the same magnitude pointing in the opposite direction.
@@ -162,11 +168,18 @@ impl Vector {
     }
 }
 
+impl Transformable for Vector {
+    /// Applies the linear part of `transform`, as vectors have no position
+    fn transform(&self, transform: Transform) -> Self {
+        transform.map_vector(*self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use nearly_eq::assert_nearly_eq;
-    use std::f64::consts::{FRAC_PI_2, PI};
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
 
     #[test]
     #[allow(clippy::eq_op)]
@@ -633,7 +646,7 @@ mod tests {
         let vector = Vector { x: 5.0, y: 10.0 };
         let rotated_vector = vector.rotate(Radians::try_new(FRAC_PI_2).unwrap());
 
-        let expected_vector = Vector { x: -10.0, y: 5.0 };
+        let expected_vector = Vector { x: 10.0, y: -5.0 };
         assert_nearly_eq!(expected_vector.x, rotated_vector.x);
         assert_nearly_eq!(expected_vector.y, rotated_vector.y);
     }
@@ -660,6 +673,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn vector_rotated_clockwise_by_zero_does_not_change() {
         let vector = Vector { x: 5.0, y: 10.0 };
         let rotated_vector = vector.rotate_clockwise(Radians::try_new(0.0).unwrap());
@@ -669,6 +683,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn vector_rotated_clockwise_by_pi_is_correct() {
         let vector = Vector { x: 5.0, y: 10.0 };
         let rotated_vector = vector.rotate_clockwise(Radians::try_new(PI).unwrap());
@@ -679,6 +694,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn vector_rotated_clockwise_by_half_pi_is_correct() {
         let vector = Vector { x: 5.0, y: 10.0 };
         let rotated_vector = vector.rotate_clockwise(Radians::try_new(FRAC_PI_2).unwrap());
@@ -689,6 +705,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn vector_rotated_clockwise_by_two_pi_is_correct() {
         let vector = Vector { x: 5.0, y: 10.0 };
         let rotated_vector = vector.rotate_clockwise(Radians::try_new(1.999_999_999 * PI).unwrap());
@@ -698,6 +715,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn vector_rotated_clockwise_twice_by_pi_is_correct() {
         let vector = Vector { x: 5.0, y: 10.0 };
 
@@ -710,17 +728,41 @@ mod tests {
     }
 
     #[test]
-    fn vector_rotated_clockwise_then_counterclockwise_is_unchanged() {
+    fn vector_rotated_by_opposite_angles_is_unchanged() {
         let vector = Vector { x: 5.0, y: 10.0 };
 
-        let rotation = Radians::try_new(1.234).unwrap();
-        let rotated_vector = vector.rotate_clockwise(rotation);
-        let rotated_vector = rotated_vector.rotate(rotation);
+        let rotated_vector = vector.rotate(Radians::try_new(1.234).unwrap());
+        let rotated_vector = rotated_vector.rotate(Radians::try_new(TAU - 1.234).unwrap());
 
         assert_nearly_eq!(vector.x, rotated_vector.x);
         assert_nearly_eq!(vector.y, rotated_vector.y);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn vector_rotated_clockwise_matches_rotate() {
+        let vector = Vector { x: 5.0, y: 10.0 };
+        let rotation = Radians::try_new(1.234).unwrap();
+
+        assert_eq!(vector.rotate(rotation), vector.rotate_clockwise(rotation));
+    }
+
+    #[test]
+    fn vector_rotates_like_the_points_it_connects() {
+        let start = Point { x: 3.0, y: -1.0 };
+        let end = Point { x: -2.0, y: 4.0 };
+        let rotation = Radians::try_new(2.5).unwrap();
+        let center = Point { x: 7.0, y: 7.0 };
+
+        let rotated_vector = Vector::from(end - start).rotate(rotation);
+        let expected_vector = Vector::from(
+            end.rotate_around_point(rotation, center) - start.rotate_around_point(rotation, center),
+        );
+
+        assert_nearly_eq!(expected_vector.x, rotated_vector.x);
+        assert_nearly_eq!(expected_vector.y, rotated_vector.y);
+    }
+
     #[test]
     fn negative_works_with_zero_vector() {
         let vector = Vector::default();
@@ -0,0 +1,366 @@
+//! Voronoi diagrams of seed points
+
+use crate::polygon::{clip_to_half_plane, polygon_from_clipped_vertices};
+use crate::{Aabb, DelaunayTriangulation, Point, Polygon, Vector};
+use std::error::Error;
+use std::fmt;
+
+/// Relative tolerance under which two points or a point and a line are considered to touch
+const TOLERANCE: f64 = 0.000_000_001;
+
+/// A [Voronoi diagram], which divides an area into one cell per seed point.
+/// Each cell covers the part of the area that is closer to its seed than to any other seed.
+///
+/// ```other
+/// ┼─────────────────────────────────────── x
+/// │
+/// │   ┌───────────┬─────────┐
+/// │   │  ·        │         │
+/// │   │          ╱     ·    │
+/// │   ├─────────┤           │
+/// │   │     ·    ╲     ·    │
+/// │   │           ├─────────┤
+/// │   └───────────┴─────────┘
+/// y
+/// ```
+///
+/// [Voronoi diagram]: https://en.wikipedia.org/wiki/Voronoi_diagram
+#[derive(Debug, PartialEq, Clone)]
+pub struct VoronoiDiagram {
+    cells: Vec<Option<Polygon>>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl VoronoiDiagram {
+    /// Computes the Voronoi cells of `seeds`, clipped to `bounds`.
+    ///
+    /// The cells are derived from the [`DelaunayTriangulation`] of the seeds:
+    /// Every cell starts out as `bounds` and is then cut by the perpendicular
+    /// bisectors between its seed and the seed's Delaunay neighbours.
+    ///
+    /// # Examples
+    /// ```
+    /// use myelin_geometry::{Aabb, Point, VoronoiDiagram};
+    ///
+    /// let bounds = Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap();
+    /// let seeds = [Point { x: 2.0, y: 5.0 }, Point { x: 8.0, y: 5.0 }];
+    ///
+    /// let voronoi_diagram = VoronoiDiagram::try_new(&seeds, bounds).expect("Invalid seeds");
+    /// let left_cell = voronoi_diagram.cell(0).expect("Seed has no cell");
+    /// assert!(left_cell.contains_point(Point { x: 4.0, y: 9.0 }));
+    /// assert!(!left_cell.contains_point(Point { x: 6.0, y: 9.0 }));
+    /// assert_eq!(&[1], voronoi_diagram.neighbours(0));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`VoronoiError`] if no seeds are given, if any seed is `NaN` or infinite,
+    /// or if a seed is repeated.
+    ///
+    /// [`DelaunayTriangulation`]: ./struct.DelaunayTriangulation.html
+    /// [`VoronoiError`]: ./enum.VoronoiError.html
+    pub fn try_new(seeds: &[Point], bounds: Aabb) -> Result<Self, VoronoiError> {
+        validate_seeds(seeds)?;
+
+        // Seeds that are too few or collinear can't be triangulated,
+        // in which case every seed is treated as a potential neighbour
+        let candidate_neighbours = DelaunayTriangulation::try_new(seeds)
+            .map(|triangulation| triangulation.adjacency())
+            .unwrap_or_else(|_| {
+                (0..seeds.len())
+                    .map(|seed| (0..seeds.len()).filter(|&other| other != seed).collect())
+                    .collect()
+            });
+
+        let tolerance = TOLERANCE
+            * Vector::from(bounds.lower_right - bounds.upper_left)
+                .magnitude()
+                .max(1.0);
+
+        let mut adjacency = vec![Vec::new(); seeds.len()];
+        let cells = (0..seeds.len())
+            .map(|seed| {
+                let vertices = candidate_neighbours[seed].iter().fold(
+                    bounds_vertices(bounds),
+                    |vertices, &neighbour| {
+                        let bisector = Bisector::new(seeds[seed], seeds[neighbour]);
+                        clip_to_half_plane(&vertices, bisector.midpoint, bisector.normal)
+                    },
+                );
+                let cell = polygon_from_clipped_vertices(vertices, tolerance)?;
+
+                for &neighbour in &candidate_neighbours[seed] {
+                    let bisector = Bisector::new(seeds[seed], seeds[neighbour]);
+                    if has_edge_on_bisector(cell.vertices(), &bisector, tolerance) {
+                        adjacency[seed].push(neighbour);
+                        adjacency[neighbour].push(seed);
+                    }
+                }
+
+                Some(cell)
+            })
+            .collect();
+
+        for neighbours in &mut adjacency {
+            neighbours.sort_unstable();
+            neighbours.dedup();
+        }
+
+        Ok(Self { cells, adjacency })
+    }
+
+    /// Returns the cell of every seed, indexed like the seeds.
+    /// Seeds whose cell doesn't cover any area within the bounds have no cell.
+    pub fn cells(&self) -> &[Option<Polygon>] {
+        &self.cells
+    }
+
+    /// Returns the cell of the seed at index `seed`, if it covers any area within the bounds
+    ///
+    /// # Panics
+    /// Panics if `seed` is out of bounds.
+    pub fn cell(&self, seed: usize) -> Option<&Polygon> {
+        self.cells[seed].as_ref()
+    }
+
+    /// Returns the sorted indices of the seeds whose cells share an edge
+    /// with the cell of the seed at index `seed`
+    ///
+    /// # Panics
+    /// Panics if `seed` is out of bounds.
+    pub fn neighbours(&self, seed: usize) -> &[usize] {
+        &self.adjacency[seed]
+    }
+
+    /// Returns the neighbours of every seed, as reported by [`VoronoiDiagram::neighbours`]
+    ///
+    /// [`VoronoiDiagram::neighbours`]: ./struct.VoronoiDiagram.html#method.neighbours
+    pub fn adjacency(&self) -> &[Vec<usize>] {
+        &self.adjacency
+    }
+}
+
+fn validate_seeds(seeds: &[Point]) -> Result<(), VoronoiError> {
+    if seeds.is_empty() {
+        return Err(VoronoiError::NoSeeds);
+    }
+
+    if let Some(index) = seeds
+        .iter()
+        .position(|seed| !seed.x.is_finite() || !seed.y.is_finite())
+    {
+        return Err(VoronoiError::NonFiniteSeed { index });
+    }
+
+    if let Some(index) = (1..seeds.len()).find(|&index| seeds[..index].contains(&seeds[index])) {
+        return Err(VoronoiError::DuplicateSeed { index });
+    }
+
+    Ok(())
+}
+
+/// Returns the corners of `bounds` in counter clockwise order
+fn bounds_vertices(bounds: Aabb) -> Vec<Point> {
+    vec![
+        bounds.upper_left,
+        Point {
+            x: bounds.lower_right.x,
+            y: bounds.upper_left.y,
+        },
+        bounds.lower_right,
+        Point {
+            x: bounds.upper_left.x,
+            y: bounds.lower_right.y,
+        },
+    ]
+}
+
+/// The perpendicular bisector between a seed and one of its neighbours
+#[derive(Debug, Copy, Clone)]
+struct Bisector {
+    midpoint: Point,
+    /// Points from the seed towards the neighbour
+    normal: Vector,
+}
+
+impl Bisector {
+    fn new(seed: Point, neighbour: Point) -> Self {
+        Self {
+            midpoint: Point {
+                x: (seed.x + neighbour.x) / 2.0,
+                y: (seed.y + neighbour.y) / 2.0,
+            },
+            normal: Vector::from(neighbour - seed).unit(),
+        }
+    }
+
+    /// Returns the distance of `point` to the bisector.
+    /// The distance is negative on the seed's side.
+    fn signed_distance(&self, point: Point) -> f64 {
+        Vector::from(point - self.midpoint).dot_product(self.normal)
+    }
+}
+
+fn has_edge_on_bisector(vertices: &[Point], bisector: &Bisector, tolerance: f64) -> bool {
+    (0..vertices.len()).any(|index| {
+        let start = vertices[index];
+        let end = vertices[(index + 1) % vertices.len()];
+        bisector.signed_distance(start).abs() <= tolerance
+            && bisector.signed_distance(end).abs() <= tolerance
+            && Vector::from(end - start).magnitude() > tolerance
+    })
+}
+
+/// The reason why a [`VoronoiDiagram`] could not be created
+///
+/// [`VoronoiDiagram`]: ./struct.VoronoiDiagram.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum VoronoiError {
+    /// No seeds were given
+    NoSeeds,
+    /// A seed had a coordinate that was `NaN` or infinite
+    NonFiniteSeed {
+        /// The index of the offending seed
+        index: usize,
+    },
+    /// A seed was given more than once
+    DuplicateSeed {
+        /// The index of the repetition
+        index: usize,
+    },
+}
+
+impl fmt::Display for VoronoiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoronoiError::NoSeeds => write!(f, "At least one seed must be given"),
+            VoronoiError::NonFiniteSeed { index } => {
+                write!(f, "Seed {} has a coordinate that is NaN or infinite", index)
+            }
+            VoronoiError::DuplicateSeed { index } => {
+                write!(f, "Seed {} repeats an earlier seed", index)
+            }
+        }
+    }
+}
+
+impl Error for VoronoiError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimplePolygon;
+    use nearly_eq::assert_nearly_eq;
+
+    fn bounds() -> Aabb {
+        Aabb::try_new((0.0, 0.0), (10.0, 10.0)).unwrap()
+    }
+
+    fn area(polygon: &Polygon) -> f64 {
+        SimplePolygon::from(polygon.clone()).area()
+    }
+
+    #[test]
+    fn single_seed_covers_bounds() {
+        let voronoi_diagram =
+            VoronoiDiagram::try_new(&[Point { x: 3.0, y: 3.0 }], bounds()).unwrap();
+
+        assert_nearly_eq!(100.0, area(voronoi_diagram.cell(0).unwrap()));
+        assert!(voronoi_diagram.neighbours(0).is_empty());
+    }
+
+    #[test]
+    fn collinear_seeds_form_strips() {
+        let seeds = [
+            Point { x: 1.0, y: 5.0 },
+            Point { x: 3.0, y: 5.0 },
+            Point { x: 7.0, y: 5.0 },
+        ];
+
+        let voronoi_diagram = VoronoiDiagram::try_new(&seeds, bounds()).unwrap();
+
+        assert_nearly_eq!(20.0, area(voronoi_diagram.cell(0).unwrap()));
+        assert_nearly_eq!(30.0, area(voronoi_diagram.cell(1).unwrap()));
+        assert_nearly_eq!(50.0, area(voronoi_diagram.cell(2).unwrap()));
+        assert_eq!(&[vec![1], vec![0, 2], vec![1]], voronoi_diagram.adjacency());
+    }
+
+    #[test]
+    fn diagonal_quadrants_are_not_adjacent() {
+        let seeds = [
+            Point { x: 2.5, y: 2.5 },
+            Point { x: 7.5, y: 2.5 },
+            Point { x: 7.5, y: 7.5 },
+            Point { x: 2.5, y: 7.5 },
+        ];
+
+        let voronoi_diagram = VoronoiDiagram::try_new(&seeds, bounds()).unwrap();
+
+        for cell in voronoi_diagram.cells() {
+            assert_nearly_eq!(25.0, area(cell.as_ref().unwrap()));
+        }
+        assert_eq!(&[1, 3], voronoi_diagram.neighbours(0));
+        assert_eq!(&[0, 2], voronoi_diagram.neighbours(1));
+    }
+
+    #[test]
+    fn cells_partition_bounds() {
+        let seeds: Vec<_> = (0..30)
+            .map(|index| {
+                let index = f64::from(index);
+                Point {
+                    x: 5.0 + (index * 7.3).sin() * 4.5,
+                    y: 5.0 + (index * 3.1).cos() * 4.5,
+                }
+            })
+            .collect();
+
+        let voronoi_diagram = VoronoiDiagram::try_new(&seeds, bounds()).unwrap();
+
+        let total_area: f64 = voronoi_diagram
+            .cells()
+            .iter()
+            .map(|cell| area(cell.as_ref().unwrap()))
+            .sum();
+        assert_nearly_eq!(100.0, total_area, 0.000_001);
+        for (seed, cell) in seeds.iter().zip(voronoi_diagram.cells()) {
+            assert!(cell.as_ref().unwrap().contains_point(*seed));
+        }
+        for (seed, neighbours) in voronoi_diagram.adjacency().iter().enumerate() {
+            for &neighbour in neighbours {
+                assert!(voronoi_diagram.neighbours(neighbour).contains(&seed));
+            }
+        }
+    }
+
+    #[test]
+    fn seed_far_outside_bounds_has_no_cell() {
+        let seeds = [Point { x: 5.0, y: 5.0 }, Point { x: 50.0, y: 5.0 }];
+
+        let voronoi_diagram = VoronoiDiagram::try_new(&seeds, bounds()).unwrap();
+
+        assert!(voronoi_diagram.cell(0).is_some());
+        assert_eq!(None, voronoi_diagram.cell(1));
+        assert!(voronoi_diagram.neighbours(0).is_empty());
+    }
+
+    #[test]
+    fn try_new_errors_for_no_seeds() {
+        assert_eq!(
+            Err(VoronoiError::NoSeeds),
+            VoronoiDiagram::try_new(&[], bounds())
+        );
+    }
+
+    #[test]
+    fn try_new_errors_for_duplicate_seed() {
+        let seeds = [
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 2.0, y: 1.0 },
+            Point { x: 1.0, y: 1.0 },
+        ];
+        assert_eq!(
+            Err(VoronoiError::DuplicateSeed { index: 2 }),
+            VoronoiDiagram::try_new(&seeds, bounds())
+        );
+    }
+}